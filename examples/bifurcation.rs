@@ -0,0 +1,63 @@
+use attract::{Bar, Generator, Henon, Settings, Sweep, render_bifurcation};
+use nalgebra::Complex;
+use ndarray::Array2;
+use photo::Image;
+use serde_yaml::from_str;
+use std::{env::args, fs::read_to_string, process::exit, sync::Arc};
+
+#[path = "./common/mod.rs"]
+mod common;
+use common::prelude::*;
+
+type Precision = f32;
+
+const COLOUR_MAP_FILE: &str = "input/colour_maps.yaml";
+
+fn read_output_filepath() -> String {
+    let args: Vec<String> = args().collect();
+    if args.len() != 2 {
+        eprintln!("Usage: {} <output filepath>", args[0]);
+        exit(1);
+    }
+    args[1].to_string()
+}
+
+fn main() {
+    let output_filepath = read_output_filepath();
+
+    // Load colour map
+    let colour_maps_str = read_to_string(COLOUR_MAP_FILE).unwrap();
+    let colour_maps: ColourMaps = from_str(&colour_maps_str).unwrap();
+    let cmap = colour_maps.build("magma");
+
+    // Sweep the 'a' parameter of the Henon map, plotting the x coordinate
+    let sweep = Sweep {
+        family: Box::new(|a| Box::new(Henon::new(a, 0.3))),
+        observable: Arc::new(|p: &Complex<Precision>| p.re),
+        parameter_range: [1.0, 1.4],
+        value_range: [-1.5, 1.5],
+    };
+    let settings = Settings::builder(Henon::new(1.0, 0.3))
+        .generator(Generator::Gaussian {
+            centre: Complex::new(0.0, 0.0),
+            std_dev: 0.1,
+        })
+        .resolution([1024, 2048])
+        .num_samples(10)
+        .max_iter(10000)
+        .warmup(1000)
+        .progress(Bar::new())
+        .build()
+        .unwrap();
+
+    // Render the diagram
+    let data: Array2<u32> = render_bifurcation(&settings, &sweep).unwrap();
+
+    // Normalise samples
+    let max = *data.iter().max().unwrap() as Precision;
+    let data = data.mapv(|v| (v as Precision).ln_1p() / max.ln_1p());
+
+    // Colourise and save
+    let img = data.mapv(|v| cmap.sample(v));
+    img.save(output_filepath).unwrap();
+}
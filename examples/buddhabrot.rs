@@ -0,0 +1,59 @@
+use attract::{Escape, Generator, Mandelbrot, Settings, ToneMap, render};
+use nalgebra::Complex;
+use photo::Image;
+use serde_yaml::from_str;
+use std::{env::args, fs::read_to_string, process::exit};
+
+#[path = "./common/mod.rs"]
+mod common;
+use common::prelude::*;
+
+type Precision = f32;
+
+const COLOUR_MAP_FILE: &str = "input/colour_maps.yaml";
+
+fn read_arguments() -> (Escape<Precision>, String) {
+    let args: Vec<String> = args().collect();
+    let escape = match (args.len(), args.get(1).map(String::as_str)) {
+        (3, Some("escaping")) => Escape::Escaping(2.0),
+        (3, Some("bounded")) => Escape::Bounded(2.0),
+        _ => {
+            eprintln!("Usage: {} <escaping|bounded> <output filepath>", args[0]);
+            exit(1);
+        }
+    };
+    (escape, args[2].to_string())
+}
+
+fn main() {
+    let (escape, output_filepath) = read_arguments();
+
+    // Load colour map
+    let colour_maps_str = read_to_string(COLOUR_MAP_FILE).unwrap();
+    let colour_maps: ColourMaps = from_str(&colour_maps_str).unwrap();
+    let cmap = colour_maps.build("inferno");
+
+    // Sample the parameter c of each orbit from the region containing the Mandelbrot set
+    let generator = Generator::Aabb {
+        centre: Complex::new(-0.5, 0.0),
+        half_size: Complex::new(1.5, 1.5),
+    };
+    let settings = Settings::builder(Mandelbrot::new())
+        .generator(generator)
+        .offset([-0.5, 0.0])
+        .scale(3.0)
+        .num_samples(10000000)
+        .num_groups(100)
+        .warmup(0)
+        .escape(escape)
+        .build()
+        .unwrap();
+
+    // Render the orbit density
+    let data = render(&settings);
+
+    // Colourise and save
+    let data = ToneMap::<Precision>::new().sqrt().apply(&data);
+    let img = data.mapv(|v| cmap.sample(v));
+    img.save(output_filepath).unwrap();
+}
@@ -1,37 +1,6 @@
-use attract::Generator;
+use attract::RenderConfig;
 use nav::Transform;
-use serde::{Deserialize, Serialize};
-
-use crate::common::{attractor_builder::AttractorBuilder, generator_builder::GeneratorBuilder};
-
-/// Configuration settings for rendering.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct RenderingSettings<T> {
-    /// Resolution of the output image [height, width].
-    pub resolution: [usize; 2],
-    /// Offset of the rendering viewport in the complex plane [real, imag].
-    pub offset: [T; 2],
-    /// Scale factor for the rendering viewport (scales imaginary axis directly, real axis is scaled by the aspect ratio set by `resolution`).
-    pub scale: T,
-}
-
-/// Configuration settings for processing.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ProcessingSettings {
-    /// Number of sample points to generate.
-    pub num_samples: usize,
-    /// Number of parallel groups for multi-threaded rendering.
-    pub num_groups: usize,
-}
-
-/// Configuration settings for rendering.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SimulationSettings {
-    /// Maximum number of iterations per sample point.
-    pub max_iter: usize,
-    /// Number of warmup iterations before plotting point positions.
-    pub warmup: usize,
-}
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 /// Configuration settings for post-processing.
 #[derive(Debug, Serialize, Deserialize)]
@@ -48,21 +17,30 @@ pub struct PostProcessingSettings {
     pub transform: Option<Transform>,
 }
 
-/// Complete configuration settings.
-#[derive(Debug, Serialize, Deserialize)]
+/// Post-processing section of a configuration file.
+#[derive(Deserialize)]
+struct PostProcessingSection {
+    post_processing: PostProcessingSettings,
+}
+
+/// Complete configuration settings: the render configuration of the library, plus post-processing.
+#[derive(Debug, Serialize)]
 pub struct Configuration<T> {
-    /// Attractor settings.
-    pub attractor: AttractorBuilder<T>,
-    /// Generator settings.
-    pub generator: GeneratorBuilder<T>,
-    /// Number of frames to generate.
-    pub num_frames: usize,
-    /// Rendering settings.
-    pub rendering: RenderingSettings<T>,
-    /// Processing settings.
-    pub processing: ProcessingSettings,
-    /// Simulation settings.
-    pub simulation: SimulationSettings,
+    /// Render settings.
+    #[serde(flatten)]
+    pub render: RenderConfig<T>,
     /// Post-processing settings.
     pub post_processing: PostProcessingSettings,
 }
+
+impl<T: DeserializeOwned> Configuration<T> {
+    /// Reads a configuration from YAML.
+    ///
+    /// Tagged enums cannot be deserialised through a flattened field,
+    /// so the render configuration and the post-processing section are read separately.
+    pub fn from_yaml(source: &str) -> Result<Self, serde_yaml::Error> {
+        let render = serde_yaml::from_str(source)?;
+        let PostProcessingSection { post_processing } = serde_yaml::from_str(source)?;
+        Ok(Self { render, post_processing })
+    }
+}
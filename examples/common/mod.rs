@@ -1,17 +1,11 @@
-#![allow(unused_imports)]
+#![allow(dead_code, unused_imports)]
 
-mod attractor_builder;
 mod colour_maps;
+#[cfg(feature = "serde")]
 mod configuration;
-mod generator_builder;
-mod parameter;
 
 pub mod prelude {
-    pub use crate::common::{
-        attractor_builder::AttractorBuilder,
-        colour_maps::ColourMaps,
-        configuration::{Configuration, PostProcessingSettings, ProcessingSettings, RenderingSettings, SimulationSettings},
-        generator_builder::GeneratorBuilder,
-        parameter::Parameter,
-    };
+    pub use crate::common::colour_maps::ColourMaps;
+    #[cfg(feature = "serde")]
+    pub use crate::common::configuration::{Configuration, PostProcessingSettings};
 }
@@ -0,0 +1,55 @@
+use attract::{Channel, Clifford, Generator, Layers, Modulated, Schedule, Settings, ToneMap, render_channels};
+use chromatic::Hsv;
+use nalgebra::Complex;
+use ndarray::Zip;
+use photo::Image;
+use std::{env::args, process::exit};
+
+type Precision = f32;
+
+const MAX_ITER: usize = 10000;
+
+fn read_arguments() -> String {
+    let args: Vec<String> = args().collect();
+    if args.len() != 2 {
+        eprintln!("Usage: {} <output filepath>", args[0]);
+        exit(1);
+    }
+    args[1].to_string()
+}
+
+fn main() {
+    let output_filepath = read_arguments();
+
+    // Drift the parameter c of a Clifford attractor over the course of each orbit
+    let attractor = Modulated::new(
+        Schedule::Ramp {
+            start: 1.0,
+            end: 1.6,
+            length: MAX_ITER as Precision,
+        },
+        |c| Clifford::new(-1.4, 1.6, c, 0.7),
+    );
+    let generator = Generator::Gaussian {
+        centre: Complex::new(0.0, 0.0),
+        std_dev: 1.0,
+    };
+    let settings = Settings::builder(attractor)
+        .generator(generator)
+        .scale(5.0)
+        .num_samples(100000)
+        .num_groups(100)
+        .max_iter(MAX_ITER)
+        .warmup(0)
+        .build()
+        .unwrap();
+    let layers: Layers<Precision> = render_channels(&settings, &[Channel::Iteration]);
+
+    // Map hue to the mean iteration, and so the parameter value, and brightness to density
+    let brightness = ToneMap::<Precision>::new().log().apply(layers.counts());
+    let iteration = layers.mean(0);
+    let img = Zip::from(&iteration)
+        .and(&brightness)
+        .map_collect(|&n, &value| Hsv::new(270.0 * n / MAX_ITER as Precision, 0.8, value));
+    img.save(output_filepath).unwrap();
+}
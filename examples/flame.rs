@@ -0,0 +1,85 @@
+use attract::{
+    Affine, Channel, Flame, FlameState, FlameTransform, Generator, Layers, Settings, ToneMap, Variation, render_channels,
+};
+use chromatic::{Colour, Lab};
+use nalgebra::Complex;
+use ndarray::Zip;
+use photo::Image;
+use serde_yaml::from_str;
+use std::{env::args, fs::read_to_string, process::exit};
+
+#[path = "./common/mod.rs"]
+mod common;
+use common::prelude::*;
+
+type Precision = f32;
+
+const COLOUR_MAP_FILE: &str = "input/colour_maps.yaml";
+
+fn read_output_filepath() -> String {
+    let args: Vec<String> = args().collect();
+    if args.len() != 2 {
+        eprintln!("Usage: {} <output filepath>", args[0]);
+        exit(1);
+    }
+    args[1].to_string()
+}
+
+fn main() {
+    let output_filepath = read_output_filepath();
+
+    // Load colour map
+    let colour_maps_str = read_to_string(COLOUR_MAP_FILE).unwrap();
+    let colour_maps: ColourMaps = from_str(&colour_maps_str).unwrap();
+    let cmap = colour_maps.build("viridis");
+
+    // Sierpinski-like contractions bent by swirl, spherical and julia variations
+    let flame = Flame::new(
+        vec![
+            FlameTransform::new(
+                1.0,
+                Affine::new(0.5, 0.0, -0.5, 0.0, 0.5, -0.5),
+                vec![(Variation::Linear, 0.8), (Variation::Swirl, 0.2)],
+                0.0,
+            ),
+            FlameTransform::new(
+                1.0,
+                Affine::new(0.5, 0.0, 0.5, 0.0, 0.5, -0.5),
+                vec![(Variation::Linear, 0.6), (Variation::Spherical, 0.4)],
+                0.5,
+            ),
+            FlameTransform::new(
+                1.0,
+                Affine::new(0.5, 0.0, 0.0, 0.0, 0.5, 0.5),
+                vec![(Variation::Linear, 0.7), (Variation::Julia, 0.3)],
+                1.0,
+            ),
+        ],
+        Some(FlameTransform::linear(1.0, Affine::new(0.0, -1.0, 0.0, 1.0, 0.0, 0.0), 0.0)),
+    );
+
+    // Render the density together with the mean colour index of each pixel
+    let generator = Generator::Gaussian {
+        centre: Complex::new(0.0, 0.0),
+        std_dev: 0.5,
+    };
+    let settings = Settings::builder(flame)
+        .generator(generator)
+        .scale(3.0)
+        .num_samples(100000)
+        .num_groups(100)
+        .seed(0)
+        .warmup(20)
+        .build()
+        .unwrap();
+    let colour = Channel::Scalar(Box::new(|p: &FlameState<Precision>| p.colour));
+    let layers: Layers<Precision> = render_channels(&settings, &[colour]);
+
+    // Map the colour index through the colour map and brightness to density
+    let brightness = ToneMap::<Precision>::new().log().apply(layers.counts());
+    let black = Lab::new(0.0, 0.0, 0.0);
+    let img = Zip::from(&layers.mean(0))
+        .and(&brightness)
+        .map_collect(|&index, &value| Lab::lerp(&black, &cmap.sample(index), value));
+    img.save(output_filepath).unwrap();
+}
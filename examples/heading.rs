@@ -0,0 +1,53 @@
+use attract::{BuiltAttractor, Channel, Layers, Planar, Registry, ToneMap, render_channels};
+use chromatic::Hsv;
+use ndarray::Zip;
+use photo::Image;
+use std::{env::args, error::Error, fs::read_to_string, process::exit};
+
+#[path = "./common/mod.rs"]
+mod common;
+use common::prelude::*;
+
+type Precision = f32;
+
+fn read_arguments() -> (String, String) {
+    let args: Vec<String> = args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: {} <parameters filepath> <output filepath>", args[0]);
+        exit(1);
+    }
+    (args[1].to_string(), args[2].to_string())
+}
+
+fn run(config_filepath: &str, output_filepath: &str) -> Result<(), Box<dyn Error>> {
+    // Read input configuration
+    let config_str = read_to_string(config_filepath)?;
+    let config = Configuration::<Precision>::from_yaml(&config_str)?.render;
+
+    // Build the attractor
+    let BuiltAttractor::Planar(attractor) = config.attractor(0, &Registry::new())? else {
+        return Err("Colouring by heading requires a planar attractor".into());
+    };
+
+    // Render the density together with the mean heading of each pixel
+    let settings = config.settings(attractor, Box::new(Planar::new()))?;
+    let layers: Layers<Precision> = render_channels(&settings, &[Channel::Heading]);
+
+    // Map hue to direction and brightness to density
+    let brightness = ToneMap::<Precision>::new().log().apply(layers.counts());
+    let heading = layers.mean(0);
+    let img = Zip::from(&heading)
+        .and(&brightness)
+        .map_collect(|&angle, &value| Hsv::new(angle.to_degrees() + 180.0, 0.8, value));
+    img.save(output_filepath)?;
+
+    Ok(())
+}
+
+fn main() {
+    let (config_filepath, output_filepath) = read_arguments();
+    if let Err(error) = run(&config_filepath, &output_filepath) {
+        eprintln!("Error: {error}");
+        exit(1);
+    }
+}
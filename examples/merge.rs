@@ -0,0 +1,21 @@
+use attract::{Checkpoint, merge};
+use std::{env::args, process::exit};
+
+fn main() {
+    let args: Vec<String> = args().collect();
+    if args.len() < 3 {
+        eprintln!("Usage: {} <output checkpoint> <input checkpoints...>", args[0]);
+        exit(1);
+    }
+
+    // Sum into 64-bit counts, so bright pixels of the inputs do not saturate
+    let total: Checkpoint<u64> = merge(&args[2..]).unwrap_or_else(|err| {
+        eprintln!("Failed to merge checkpoints: {err}");
+        exit(1);
+    });
+    total.save(&args[1]).unwrap();
+    println!("Merged {} samples into {}", total.num_samples, args[1]);
+    if total.saturated_pixels() > 0 {
+        eprintln!("Warning: {} pixels saturated, so their counts are clamped", total.saturated_pixels());
+    }
+}
@@ -1,7 +1,11 @@
-use attract::{Settings, render};
+use attract::{
+    Attractor, BuiltAttractor, Checkpoint, Planar, Projection, Registry, RenderConfig, State, ToneMap, render_resumable,
+    saturated_pixels, try_render,
+};
+use ndarray::Array2;
 use photo::Image;
 use serde_yaml::from_str;
-use std::{env::args, fs::read_to_string, process::exit};
+use std::{env::args, error::Error, fs::read_to_string, path::Path, process::exit};
 
 #[path = "./common/mod.rs"]
 mod common;
@@ -20,41 +24,66 @@ fn read_parameters_filepath() -> String {
     args[1].to_string()
 }
 
-fn main() {
+fn render_frame<S: State<Precision> + 'static>(
+    config: &RenderConfig<Precision>,
+    frame: usize,
+    attractor: Box<dyn Send + Sync + Attractor<Precision, S>>,
+    projection: Box<dyn Send + Sync + Projection<Precision, S>>,
+) -> Result<Array2<u32>, Box<dyn Error>>
+where
+    Planar: Projection<Precision, S>,
+{
+    let settings = config.settings(attractor, projection)?;
+
+    // Resume from and periodically write to a checkpoint file if requested
+    let Some(checkpoint) = &config.processing.checkpoint else {
+        return Ok(try_render(&settings)?);
+    };
+    let path = if config.num_frames > 1 {
+        checkpoint.path.replace(".bin", &format!("_{frame}.bin"))
+    } else {
+        checkpoint.path.clone()
+    };
+    let path = Path::new(&path);
+    let start = if path.exists() {
+        Checkpoint::load(path)?
+    } else {
+        Checkpoint::new(&settings)
+    };
+    Ok(render_resumable(&settings, start, Some(path), checkpoint.batch_size)?.counts)
+}
+
+fn run(config_filepath: &str) -> Result<(), Box<dyn Error>> {
     // Read input configuration
-    let config_filepath = read_parameters_filepath();
-    let config_str = read_to_string(config_filepath).unwrap();
-    let config = from_str::<Configuration<Precision>>(&config_str).unwrap();
+    let config_str = read_to_string(config_filepath)?;
+    let config = Configuration::<Precision>::from_yaml(&config_str)?;
+    let num_frames = config.render.num_frames;
 
     // Load colour maps
-    let colour_maps_str = read_to_string(COLOUR_MAP_FILE).unwrap();
-    let colour_maps: ColourMaps = from_str(&colour_maps_str).unwrap();
+    let colour_maps_str = read_to_string(COLOUR_MAP_FILE)?;
+    let colour_maps: ColourMaps = from_str(&colour_maps_str)?;
     let cmap = colour_maps.build(config.post_processing.colour_map.as_str());
 
-    // Build the generator
-    let generator = config.generator.build();
-
     // Calculate name length
-    let filename_length = (config.num_frames - 1).to_string().len();
+    let filename_length = (num_frames - 1).to_string().len();
 
     // Generate each frame
-    for n in 0..config.num_frames {
-        // Setup simulation settings
-        let attractor = config.attractor.build(n, config.num_frames);
-        let settings = Settings {
-            attractor: attractor,
-            generator: &generator,
-            resolution: config.rendering.resolution,
-            offset: config.rendering.offset,
-            scale: config.rendering.scale,
-            num_samples: config.processing.num_samples,
-            num_groups: config.processing.num_groups,
-            max_iter: config.simulation.max_iter,
-            warmup: config.simulation.warmup,
+    for n in 0..num_frames {
+        // Render the attractor
+        let mut data = match config.render.attractor(n, &Registry::new())? {
+            BuiltAttractor::Planar(attractor) => render_frame(&config.render, n, attractor, Box::new(Planar::new()))?,
+            BuiltAttractor::Spatial(attractor) => {
+                render_frame(&config.render, n, attractor, Box::new(config.render.camera(n)?))?
+            }
+            BuiltAttractor::Orbit(attractor) => render_frame(&config.render, n, attractor, Box::new(Planar::new()))?,
+            _ => return Err("Unsupported attractor state".into()),
         };
 
-        // Render the attractor
-        let mut data = render(&settings);
+        // Warn if the brightest pixels have saturated
+        let saturated = saturated_pixels(&data);
+        if saturated > 0 {
+            eprintln!("Warning: {saturated} pixels of frame {n} saturated, so their counts are clamped");
+        }
 
         // If a transform is specified, apply it
         if let Some(transform) = &config.post_processing.transform {
@@ -62,24 +91,34 @@ fn main() {
         }
 
         // Normalise samples
-        let max = *data.iter().max().unwrap() as Precision;
-        let data = if config.post_processing.apply_log {
-            data.mapv(|v| (v as Precision).ln().max(0.0) / (max as Precision).ln())
+        let tone_map = if config.post_processing.apply_log {
+            ToneMap::new().log()
         } else {
-            data.mapv(|v| v as Precision / max as Precision)
+            ToneMap::new()
         };
+        let data: Array2<Precision> = tone_map.apply(&data);
 
         // Colourise
-        let img = data.mapv(|v| cmap.sample(v as Precision));
+        let img = data.mapv(|v| cmap.sample(v));
 
         // Save the image
         let mut filename = format!("{}/{}", config.post_processing.output_dir, config.post_processing.image_name);
-        let suffix = if config.num_frames > 1 {
+        let suffix = if num_frames > 1 {
             format!("_{:0width$}.png", n, width = filename_length)
         } else {
             ".png".to_string()
         };
         filename = filename.replace(".png", &suffix);
-        img.save(filename).unwrap();
+        img.save(filename)?;
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let config_filepath = read_parameters_filepath();
+    if let Err(error) = run(&config_filepath) {
+        eprintln!("Error: {error}");
+        exit(1);
     }
 }
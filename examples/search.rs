@@ -0,0 +1,174 @@
+use attract::{
+    Accumulation, Attractor, AttractorSpec, Candidate, Clifford, DeJong, Duffing, GeneratorSpec, Henon, Ikeda, Parameter,
+    Parametric, ProcessingConfig, RenderConfig, RenderingConfig, SearchSettings, SimulationConfig, Tinkerbell, search,
+};
+use rand::rng;
+use serde_yaml::to_string;
+use std::{env::args, fs::write, path::Path, process::exit};
+
+#[path = "./common/mod.rs"]
+mod common;
+use common::prelude::*;
+
+type Precision = f64;
+
+/// Number of top-ranked candidates written to disk.
+const NUM_WINNERS: usize = 10;
+
+fn read_arguments() -> (String, usize, String) {
+    let args: Vec<String> = args().collect();
+    if args.len() != 4 {
+        eprintln!("Usage: {} <family> <num trials> <output directory>", args[0]);
+        eprintln!("Families: Clifford, DeJong, Duffing, Henon, Ikeda, Tinkerbell");
+        exit(1);
+    }
+    let num_trials = args[2].parse().unwrap_or_else(|_| {
+        eprintln!("Number of trials must be a positive integer");
+        exit(1);
+    });
+    (args[1].clone(), num_trials, args[3].clone())
+}
+
+/// Builds a ready-to-render configuration for a candidate.
+fn configuration(
+    attractor: AttractorSpec<Precision>,
+    candidate: &Candidate<Precision>,
+    settings: &SearchSettings<Precision>,
+    image_name: String,
+) -> Configuration<Precision> {
+    Configuration {
+        render: RenderConfig {
+            attractor,
+            generator: GeneratorSpec::Gaussian {
+                centre: settings.start,
+                std_dev: 0.1,
+            },
+            num_frames: 1,
+            rendering: RenderingConfig {
+                resolution: [1024, 1024],
+                offset: candidate.centre(),
+                scale: candidate.extent() * 1.1,
+                camera: None,
+            },
+            processing: ProcessingConfig {
+                num_samples: 1000000,
+                num_groups: 100,
+                seed: None,
+                accumulation: Accumulation::PerGroup,
+                checkpoint: None,
+            },
+            simulation: SimulationConfig {
+                max_iter: 10000,
+                warmup: 1000,
+                step_size: None,
+                escape: None,
+            },
+        },
+        post_processing: PostProcessingSettings {
+            apply_log: true,
+            colour_map: "magma".to_string(),
+            output_dir: "output".to_string(),
+            image_name,
+            transform: None,
+        },
+    }
+}
+
+/// Searches a family over the recommended ranges of its parameters, and writes the winning candidates
+/// as configuration files.
+///
+/// The prototype only provides the parameters of the family, so its own values are irrelevant.
+fn run<A: Attractor<Precision> + Parametric<Precision> + Sync>(
+    family: &str,
+    num_trials: usize,
+    output_dir: &str,
+    prototype: A,
+    builder: impl Fn(&[Precision]) -> AttractorSpec<Precision>,
+) {
+    let settings = SearchSettings {
+        num_trials,
+        start: [0.05, 0.05],
+        warmup: 1000,
+        iterations: 10000,
+        escape_radius: 1.0e6,
+        max_period: 64,
+        min_lyapunov: 0.01,
+        grid_resolution: 64,
+        min_coverage: 0.1,
+    };
+
+    let ranges = prototype.parameter_ranges();
+    let candidates = search(|p| prototype.with_parameters(p), &ranges, &settings, &mut rng()).unwrap();
+    println!("{} of {} trials passed", candidates.len(), num_trials);
+
+    for (rank, candidate) in candidates.iter().take(NUM_WINNERS).enumerate() {
+        let name = format!("{}_{:02}", family.to_lowercase(), rank);
+        let config = configuration(builder(&candidate.parameters), candidate, &settings, format!("{name}.png"));
+        let path = Path::new(output_dir).join(format!("{name}.yaml"));
+        write(&path, to_string(&config).unwrap()).unwrap();
+        let parameters: Vec<String> = prototype
+            .parameter_names()
+            .iter()
+            .zip(&candidate.parameters)
+            .map(|(name, value)| format!("{name} = {value:.6}"))
+            .collect();
+        println!(
+            "{}: lyapunov {:.3}, coverage {:.3}, {}",
+            path.display(),
+            candidate.lyapunov,
+            candidate.coverage,
+            parameters.join(", ")
+        );
+    }
+}
+
+fn main() {
+    let (family, num_trials, output_dir) = read_arguments();
+    let fixed = |p: &[Precision], i: usize| Parameter::Fixed(p[i]);
+
+    match family.as_str() {
+        "Clifford" => run(&family, num_trials, &output_dir, Clifford::new(0.0, 0.0, 0.0, 0.0), |p| {
+            AttractorSpec::Clifford {
+                a: fixed(p, 0),
+                b: fixed(p, 1),
+                c: fixed(p, 2),
+                d: fixed(p, 3),
+            }
+        }),
+        "DeJong" => run(&family, num_trials, &output_dir, DeJong::new(0.0, 0.0, 0.0, 0.0), |p| {
+            AttractorSpec::DeJong {
+                a: fixed(p, 0),
+                b: fixed(p, 1),
+                c: fixed(p, 2),
+                d: fixed(p, 3),
+            }
+        }),
+        "Duffing" => run(&family, num_trials, &output_dir, Duffing::new(0.0, 0.0), |p| {
+            AttractorSpec::Duffing {
+                a: fixed(p, 0),
+                b: fixed(p, 1),
+            }
+        }),
+        "Henon" => run(&family, num_trials, &output_dir, Henon::new(0.0, 0.0), |p| {
+            AttractorSpec::Henon {
+                a: fixed(p, 0),
+                b: fixed(p, 1),
+            }
+        }),
+        "Ikeda" => run(&family, num_trials, &output_dir, Ikeda::new(0.0), |p| AttractorSpec::Ikeda {
+            u: fixed(p, 0),
+        }),
+        "Tinkerbell" => run(&family, num_trials, &output_dir, Tinkerbell::new(0.0, 0.0, 0.0, 0.0), |p| {
+            AttractorSpec::Tinkerbell {
+                a: fixed(p, 0),
+                b: fixed(p, 1),
+                c: fixed(p, 2),
+                d: fixed(p, 3),
+            }
+        }),
+        _ => {
+            eprintln!("Unknown attractor family '{family}'");
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Estimation of Lyapunov exponents.
+//!
+//! Exponents are estimated by evolving a basis of tangent vectors alongside the orbit and
+//! re-orthonormalising them with a QR decomposition at every iteration. The logarithms of the
+//! diagonal of `R` accumulate into the running estimate of each exponent.
+//!
+//! States are analysed through their `N` real coordinates, so planar maps over the complex plane have two exponents
+//! and maps of `nalgebra` vectors, such as flows sampled by a `FlowMap`, have one per dimension.
+
+use nalgebra::{SMatrix, SVector, Scalar};
+use num_traits::Float;
+use std::cmp::Ordering;
+
+use crate::{Attractor, Coordinates, Jacobian};
+
+/// Estimated Lyapunov spectrum of an attractor whose state has `N` coordinates, defaulting to a planar attractor.
+///
+/// Exponents are growth rates per iteration, so those of a flow sampled at a fixed time step are divided by the step
+/// size to give growth rates per unit time.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct LyapunovSpectrum<T, const N: usize = 2> {
+    /// Final estimate of the exponents, sorted from largest to smallest.
+    pub exponents: [T; N],
+    /// Running estimate of the exponents after each iteration, each sorted from largest to smallest.
+    pub history: Vec<[T; N]>,
+    /// Number of iterations contributing to the estimate, fewer than requested if the orbit escaped.
+    pub iterations: usize,
+}
+
+impl<T: Float, const N: usize> LyapunovSpectrum<T, N> {
+    /// Returns the largest Lyapunov exponent.
+    #[must_use]
+    #[inline]
+    pub const fn largest(&self) -> T {
+        self.exponents[0]
+    }
+
+    /// Returns true if the largest exponent is positive, indicating chaotic dynamics.
+    #[must_use]
+    #[inline]
+    pub fn is_chaotic(&self) -> bool {
+        self.largest() > T::zero()
+    }
+}
+
+/// Estimates the Lyapunov spectrum of an attractor using its analytic Jacobian.
+///
+/// The orbit starts at `start` and runs for `warmup` iterations before `iterations` iterations contribute to the estimate.
+#[inline]
+pub fn lyapunov_spectrum<T, S, A, const N: usize>(
+    attractor: &A,
+    start: S,
+    warmup: usize,
+    iterations: usize,
+) -> LyapunovSpectrum<T, N>
+where
+    T: Float + Scalar,
+    S: Coordinates<T, N>,
+    A: Attractor<T, S> + Jacobian<T, S, N> + ?Sized,
+{
+    estimate(attractor, |p| attractor.jacobian(p), start, warmup, iterations)
+}
+
+/// Estimates the Lyapunov spectrum of an attractor using a central finite-difference Jacobian.
+///
+/// This is a fallback for attractors which do not implement `Jacobian`, with `epsilon` setting the difference step
+/// along each coordinate of the state.
+#[inline]
+pub fn lyapunov_spectrum_numerical<T, S, A, const N: usize>(
+    attractor: &A,
+    start: S,
+    warmup: usize,
+    iterations: usize,
+    epsilon: T,
+) -> LyapunovSpectrum<T, N>
+where
+    T: Float + Scalar,
+    S: Coordinates<T, N>,
+    A: Attractor<T, S> + ?Sized,
+{
+    let jacobian = |p: S| {
+        let two_epsilon = epsilon + epsilon;
+        let centre = p.coordinates();
+        let image = |offset: T, axis: usize| {
+            let mut shifted = centre;
+            shifted[axis] = shifted[axis] + offset;
+            attractor.iterate(S::from_coordinates(shifted)).coordinates()
+        };
+        let mut matrix = SMatrix::<T, N, N>::zeros();
+        for axis in 0..N {
+            let (forward, backward) = (image(epsilon, axis), image(-epsilon, axis));
+            for row in 0..N {
+                matrix[(row, axis)] = (forward[row] - backward[row]) / two_epsilon;
+            }
+        }
+        matrix
+    };
+    estimate(attractor, jacobian, start, warmup, iterations)
+}
+
+/// Evolves the orbit and tangent space, accumulating the QR growth rates.
+#[inline]
+fn estimate<T, S, A, const N: usize>(
+    attractor: &A,
+    jacobian: impl Fn(S) -> SMatrix<T, N, N>,
+    start: S,
+    warmup: usize,
+    iterations: usize,
+) -> LyapunovSpectrum<T, N>
+where
+    T: Float + Scalar,
+    S: Coordinates<T, N>,
+    A: Attractor<T, S> + ?Sized,
+{
+    let mut pos = start;
+
+    // Warmup phase - skip initial iterations to reach the attractor
+    for _ in 0..warmup {
+        pos = attractor.iterate(pos);
+    }
+
+    // Columns of the basis are the orthonormal tangent vectors
+    let mut basis = SMatrix::<T, N, N>::identity();
+    let mut sums = [T::zero(); N];
+    let mut history = Vec::with_capacity(iterations);
+    for n in 0..iterations {
+        let jac = jacobian(pos);
+        pos = attractor.iterate(pos);
+        if !pos.coordinates().iter().all(|coordinate| coordinate.is_finite()) {
+            break;
+        }
+
+        // Push the tangent basis forward
+        let mut pushed =
+            SMatrix::<T, N, N>::from_fn(|row, col| (0..N).fold(T::zero(), |acc, k| acc + jac[(row, k)] * basis[(k, col)]));
+
+        // Modified Gram-Schmidt QR re-orthonormalisation
+        for col in 0..N {
+            for previous in 0..col {
+                let projection = dot(&pushed.column(previous).into_owned(), &pushed.column(col).into_owned());
+                for row in 0..N {
+                    pushed[(row, col)] = pushed[(row, col)] - projection * pushed[(row, previous)];
+                }
+            }
+            let length = dot(&pushed.column(col).into_owned(), &pushed.column(col).into_owned()).sqrt();
+            for row in 0..N {
+                pushed[(row, col)] = pushed[(row, col)] / length;
+            }
+            sums[col] = sums[col] + length.ln();
+        }
+        basis = pushed;
+
+        let count = T::from(n + 1).unwrap();
+        history.push(descending(sums.map(|sum| sum / count)));
+    }
+
+    let exponents = history.last().copied().unwrap_or_else(|| [T::nan(); N]);
+    LyapunovSpectrum {
+        exponents,
+        iterations: history.len(),
+        history,
+    }
+}
+
+/// Dot product of two vectors.
+#[inline]
+fn dot<T: Float + Scalar, const N: usize>(a: &SVector<T, N>, b: &SVector<T, N>) -> T {
+    a.iter().zip(b.iter()).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+/// Orders exponents from largest to smallest.
+///
+/// The QR diagonal follows the tangent basis rather than the growth rates, so the first exponent is not always the largest.
+#[inline]
+fn descending<T: Float, const N: usize>(mut exponents: [T; N]) -> [T; N] {
+    exponents.sort_unstable_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+    exponents
+}
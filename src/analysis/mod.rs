@@ -0,0 +1,10 @@
+//! Module providing numerical analysis of attractors.
+//!
+//! Analysis tools characterise the dynamics of an attractor without rendering it,
+//! for example to decide whether a parameter set is chaotic.
+
+mod lyapunov;
+mod search;
+
+pub use lyapunov::{LyapunovSpectrum, lyapunov_spectrum, lyapunov_spectrum_numerical};
+pub use search::{Candidate, SearchSettings, search};
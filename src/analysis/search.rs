@@ -0,0 +1,227 @@
+//! Random search for chaotic attractor parameters.
+//!
+//! Parameter vectors are sampled uniformly within the given ranges, and each candidate orbit is
+//! rejected if it escapes, collapses to a fixed point, settles onto a short periodic cycle, has a
+//! non-positive largest Lyapunov exponent, or covers too little of its bounding box.
+//! The survivors are ranked by their coverage of the image plane.
+
+use nalgebra::{Complex, Scalar};
+use ndarray::Array2;
+use num_traits::Float;
+use rand::{Rng, distr::uniform::SampleUniform};
+use rayon::prelude::*;
+use std::{cmp::Ordering, iter::repeat_with};
+
+use crate::{Attractor, Error, error::check_range, lyapunov_spectrum_numerical};
+
+/// Relative tolerance used to detect fixed points and periodic cycles.
+const TOLERANCE: f64 = 1e-6;
+
+/// Step used for the finite-difference Jacobian in the Lyapunov estimate.
+const EPSILON: f64 = 1e-6;
+
+/// Configuration settings for a parameter search.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Settings struct is expected to be constructed directly."
+)]
+#[derive(Debug, Clone, Copy)]
+pub struct SearchSettings<T> {
+    /// Number of random parameter vectors to try.
+    pub num_trials: usize,
+    /// Initial point of every trial orbit [real, imag].
+    pub start: [T; 2],
+    /// Number of warmup iterations before the orbit is assessed.
+    pub warmup: usize,
+    /// Number of iterations used to assess the orbit.
+    pub iterations: usize,
+    /// Orbits reaching this distance from the origin are rejected as escaping.
+    pub escape_radius: T,
+    /// Longest cycle length detected and rejected as periodic.
+    pub max_period: usize,
+    /// Minimum largest Lyapunov exponent for an orbit to be kept.
+    pub min_lyapunov: T,
+    /// Resolution of the square grid over the orbit's bounding box used to measure coverage.
+    pub grid_resolution: usize,
+    /// Minimum fraction of grid cells visited for an orbit to be kept.
+    pub min_coverage: T,
+}
+
+impl<T> SearchSettings<T> {
+    /// Checks that the settings can be used for a search.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ZeroGridResolution`] if the coverage grid has no cells.
+    #[inline]
+    pub const fn validate(&self) -> Result<(), Error> {
+        if self.grid_resolution == 0 {
+            return Err(Error::ZeroGridResolution);
+        }
+        Ok(())
+    }
+}
+
+/// Attractor parameters which passed every search criterion.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Candidate<T> {
+    /// Parameter vector passed to the attractor constructor.
+    pub parameters: Vec<T>,
+    /// Estimated largest Lyapunov exponent.
+    pub lyapunov: T,
+    /// Fraction of the bounding box grid visited by the orbit.
+    pub coverage: T,
+    /// Estimated bounds of the attractor [[min real, max real], [min imag, max imag]].
+    pub bounds: [[T; 2]; 2],
+}
+
+impl<T: Float> Candidate<T> {
+    /// Returns the centre of the attractor bounds [real, imag].
+    #[must_use]
+    #[inline]
+    pub fn centre(&self) -> [T; 2] {
+        let two = T::one() + T::one();
+        [
+            (self.bounds[0][0] + self.bounds[0][1]) / two,
+            (self.bounds[1][0] + self.bounds[1][1]) / two,
+        ]
+    }
+
+    /// Returns the larger of the width and height of the attractor bounds.
+    #[must_use]
+    #[inline]
+    pub fn extent(&self) -> T {
+        (self.bounds[0][1] - self.bounds[0][0]).max(self.bounds[1][1] - self.bounds[1][0])
+    }
+}
+
+/// Searches for chaotic parameters of an attractor family.
+///
+/// The `build` function constructs an attractor from a parameter vector, as [`Parametric::with_parameters`](crate::Parametric::with_parameters)
+/// does, with each parameter drawn uniformly from the corresponding entry of `ranges`. Trials are evaluated
+/// in parallel and the surviving candidates are returned ranked from most to least coverage.
+///
+/// # Errors
+///
+/// Returns an error if the settings are invalid, as described in [`SearchSettings::validate`],
+/// [`Error::InvalidRange`] if a parameter range is not finite or its minimum exceeds its maximum,
+/// or the error returned by `build` if an attractor cannot be constructed.
+#[inline]
+pub fn search<T, A, B, R>(
+    build: B,
+    ranges: &[[T; 2]],
+    settings: &SearchSettings<T>,
+    rng: &mut R,
+) -> Result<Vec<Candidate<T>>, Error>
+where
+    T: Float + Scalar + SampleUniform + Send + Sync,
+    A: Attractor<T>,
+    B: Fn(&[T]) -> Result<A, Error> + Sync,
+    R: Rng,
+{
+    settings.validate()?;
+    ranges.iter().try_for_each(|&range| check_range(range))?;
+    let trials: Vec<Vec<T>> = repeat_with(|| ranges.iter().map(|&[min, max]| rng.random_range(min..=max)).collect())
+        .take(settings.num_trials)
+        .collect();
+
+    let assessed: Vec<Option<Candidate<T>>> = trials
+        .into_par_iter()
+        .map(|parameters| {
+            let attractor = build(&parameters)?;
+            Ok(assess(&attractor, settings).map(|candidate| Candidate { parameters, ..candidate }))
+        })
+        .collect::<Result<_, Error>>()?;
+    let mut candidates: Vec<Candidate<T>> = assessed.into_iter().flatten().collect();
+    candidates.sort_by(|a, b| b.coverage.partial_cmp(&a.coverage).unwrap_or(Ordering::Equal));
+    Ok(candidates)
+}
+
+/// Assesses a single attractor, returning a candidate without parameters if it passes every criterion.
+#[inline]
+fn assess<T, A>(attractor: &A, settings: &SearchSettings<T>) -> Option<Candidate<T>>
+where
+    T: Float + Scalar,
+    A: Attractor<T>,
+{
+    let escaped = |p: Complex<T>| {
+        let radius = p.re.hypot(p.im);
+        radius.is_nan() || radius >= settings.escape_radius
+    };
+
+    // Warmup phase - skip initial iterations to reach the attractor
+    let mut pos = Complex::new(settings.start[0], settings.start[1]);
+    for _ in 0..settings.warmup {
+        pos = attractor.iterate(pos);
+        if escaped(pos) {
+            return None;
+        }
+    }
+    let settled = pos;
+
+    // Record the orbit
+    let mut orbit = Vec::with_capacity(settings.iterations);
+    for _ in 0..settings.iterations {
+        pos = attractor.iterate(pos);
+        if escaped(pos) {
+            return None;
+        }
+        orbit.push(pos);
+    }
+
+    // Reject fixed points
+    let bounds = orbit.iter().fold(
+        [[T::infinity(), T::neg_infinity()], [T::infinity(), T::neg_infinity()]],
+        |[[min_re, max_re], [min_im, max_im]], p| [[min_re.min(p.re), max_re.max(p.re)], [min_im.min(p.im), max_im.max(p.im)]],
+    );
+    let width = bounds[0][1] - bounds[0][0];
+    let height = bounds[1][1] - bounds[1][0];
+    let tolerance = T::from(TOLERANCE).unwrap() * width.max(height);
+    if tolerance <= T::zero() {
+        return None;
+    }
+
+    // Reject periodic cycles
+    let last = *orbit.last()?;
+    let periodic = orbit.iter().rev().skip(1).take(settings.max_period).any(|p| {
+        let delta = *p - last;
+        delta.re.hypot(delta.im) < tolerance
+    });
+    if periodic {
+        return None;
+    }
+
+    // Measure coverage of the bounding box
+    let grid = settings.grid_resolution;
+    let cells = T::from(grid).unwrap();
+    let mut visited = Array2::from_elem([grid, grid], false);
+    for p in &orbit {
+        let row = ((p.im - bounds[1][0]) / height.max(tolerance) * cells)
+            .to_usize()
+            .unwrap_or(0)
+            .min(grid - 1);
+        let col = ((p.re - bounds[0][0]) / width.max(tolerance) * cells)
+            .to_usize()
+            .unwrap_or(0)
+            .min(grid - 1);
+        visited[[row, col]] = true;
+    }
+    let coverage = T::from(visited.iter().filter(|&&v| v).count()).unwrap() / (cells * cells);
+    if coverage < settings.min_coverage {
+        return None;
+    }
+
+    // Require chaotic dynamics
+    let lyapunov = lyapunov_spectrum_numerical(attractor, settled, 0, settings.iterations, T::from(EPSILON).unwrap()).largest();
+    if lyapunov.is_nan() || lyapunov <= settings.min_lyapunov {
+        return None;
+    }
+
+    Some(Candidate {
+        parameters: Vec::new(),
+        lyapunov,
+        coverage,
+        bounds,
+    })
+}
@@ -0,0 +1,69 @@
+//! Implementation of affine maps of the plane.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{Attractor, Jacobian};
+
+/// An affine map defined by the equations:
+/// - `x_{n+1} = a * x_n + b * y_n + c`
+/// - `y_{n+1} = d * x_n + e * y_n + f`
+///
+/// Affine maps are the building blocks of iterated function systems and fractal flames.
+#[derive(Debug, Clone, Copy)]
+pub struct Affine<T> {
+    /// Coefficient of `x` in the new `x`.
+    a: T,
+    /// Coefficient of `y` in the new `x`.
+    b: T,
+    /// Translation of `x`.
+    c: T,
+    /// Coefficient of `x` in the new `y`.
+    d: T,
+    /// Coefficient of `y` in the new `y`.
+    e: T,
+    /// Translation of `y`.
+    f: T,
+}
+
+impl<T> Affine<T> {
+    /// Creates a new `Affine` map with the specified coefficients.
+    #[expect(
+        clippy::many_single_char_names,
+        reason = "Parameter names match the conventional notation of the equations."
+    )]
+    #[inline]
+    pub const fn new(a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+}
+
+impl<T: Float> Affine<T> {
+    /// Creates the identity map.
+    #[must_use]
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero(), T::one(), T::zero())
+    }
+
+    /// Applies the map to a point.
+    #[inline]
+    pub fn apply(&self, p: Complex<T>) -> Complex<T> {
+        Complex::new(self.a * p.re + self.b * p.im + self.c, self.d * p.re + self.e * p.im + self.f)
+    }
+}
+
+impl<T: Float> Attractor<T> for Affine<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        self.apply(p)
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for Affine<T> {
+    #[inline]
+    fn jacobian(&self, _p: Complex<T>) -> Matrix2<T> {
+        Matrix2::new(self.a, self.b, self.d, self.e)
+    }
+}
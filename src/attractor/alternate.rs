@@ -0,0 +1,37 @@
+//! Implementation of the alternation of two attractors.
+
+use rand::RngCore;
+
+use crate::Attractor;
+
+/// The alternation of two attractors, applying `first` at even iterations and `second` at odd iterations.
+///
+/// Each attractor is iterated at the count `n / 2` of its own applications, so nested alternations
+/// take their turns in sequence: alternating `Alternate::new(a, c)` with `b` applies `a, b, c, b, a, ...`.
+/// Calls without an iteration index, such as `iterate`, start an orbit and so apply the first attractor.
+#[derive(Debug, Clone, Copy)]
+pub struct Alternate<A, B> {
+    /// Attractor applied at even iterations.
+    first: A,
+    /// Attractor applied at odd iterations.
+    second: B,
+}
+
+impl<A, B> Alternate<A, B> {
+    /// Creates the alternation of `first` and `second`.
+    #[inline]
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<T, S, A: Attractor<T, S>, B: Attractor<T, S>> Attractor<T, S> for Alternate<A, B> {
+    #[inline]
+    fn iterate_at(&self, n: usize, p: S, rng: &mut dyn RngCore, step: Option<T>) -> S {
+        if n.is_multiple_of(2) {
+            self.first.iterate_at(n / 2, p, rng, step)
+        } else {
+            self.second.iterate_at(n / 2, p, rng, step)
+        }
+    }
+}
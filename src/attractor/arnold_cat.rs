@@ -0,0 +1,45 @@
+//! Implementation of Arnold's cat map.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+use std::marker::PhantomData;
+
+use crate::{Attractor, Jacobian};
+
+/// Arnold's cat map on the unit torus, defined by the equations:
+/// - `x_{n+1} = 2 * x_n + y_n  (mod 1)`
+/// - `y_{n+1} = x_n + y_n  (mod 1)`
+///
+/// The map is area-preserving and mixing, so typical orbits fill the unit square uniformly.
+#[derive(Debug, Clone, Copy)]
+pub struct ArnoldCat<T> {
+    /// Phantom data to allow generic type T.
+    _phantom: PhantomData<T>,
+}
+
+impl<T> ArnoldCat<T> {
+    /// Creates a new `ArnoldCat` map.
+    /// This map has no parameters.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<T: Float + Copy> Attractor<T> for ArnoldCat<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        let x = p.re + p.re + p.im;
+        let y = p.re + p.im;
+        Complex::new(x - x.floor(), y - y.floor())
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for ArnoldCat<T> {
+    #[inline]
+    fn jacobian(&self, _p: Complex<T>) -> Matrix2<T> {
+        Matrix2::new(T::one() + T::one(), T::one(), T::one(), T::one())
+    }
+}
@@ -0,0 +1,77 @@
+//! Implementation of the Bedhead attractor.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Bedhead attractor of Ivan Emrich defined by the equations:
+/// - `x_{n+1} = sin(x_n * y_n / b) * y_n + cos(a * x_n - y_n)`
+/// - `y_{n+1} = x_n + sin(y_n) / b`
+///
+/// The canonical parameters are `a = -0.81`, `b = -0.92`.
+#[derive(Debug, Clone, Copy)]
+pub struct Bedhead<T> {
+    /// Parameter 'a' in the Bedhead attractor equation.
+    a: T,
+    /// Parameter 'b' in the Bedhead attractor equation.
+    b: T,
+}
+
+impl<T> Bedhead<T> {
+    /// Creates a new `Bedhead` attractor with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<T: Float + Copy> Attractor<T> for Bedhead<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        let (x, y) = (p.re, p.im);
+        Complex::new((x * y / self.b).sin() * y + (self.a * x - y).cos(), x + y.sin() / self.b)
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for Bedhead<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        let (sin, cos) = (x * y / self.b).sin_cos();
+        let phase = (self.a * x - y).sin();
+        Matrix2::new(
+            cos * y * y / self.b - self.a * phase,
+            cos * x * y / self.b + sin + phase,
+            T::one(),
+            y.cos() / self.b,
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Bedhead<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-1.0, 1.0]; 2])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b] = parameter_array(values)?;
+        Ok(Self::new(a, b))
+    }
+}
@@ -1,9 +1,13 @@
 //! Implementation of the Chirikov Standard Map.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 
-use crate::Attractor;
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
 
 /// The Chirikov Standard Map defined by the equations:
 /// - `p_{n+1} = p_n + K * sin(x_n)`
@@ -27,7 +31,7 @@ impl<T> Chirikov<T> {
 
 impl<T: Float + Copy> Attractor<T> for Chirikov<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         let x = p.re; // position
         let p_momentum = p.im; // momentum
 
@@ -37,3 +41,34 @@ impl<T: Float + Copy> Attractor<T> for Chirikov<T> {
         Complex::new(x_new, p_new)
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for Chirikov<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let k_cos = self.k * p.re.cos();
+        Matrix2::new(T::one() + k_cos, T::one(), k_cos, T::one())
+    }
+}
+
+impl<T: Float> Parametric<T> for Chirikov<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["k"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.k]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.0, 3.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [k] = parameter_array(values)?;
+        Ok(Self::new(k))
+    }
+}
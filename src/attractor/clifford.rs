@@ -1,9 +1,13 @@
 //! Implementation of the Clifford attractor.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 
-use crate::Attractor;
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
 
 /// The Clifford attractor defined by the equations:
 /// - `x_{n+1} = sin(a * y_n) + c * cos(a * x_n)`
@@ -30,10 +34,46 @@ impl<T> Clifford<T> {
 
 impl<T: Float + Copy> Attractor<T> for Clifford<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         Complex::new(
             (self.a * p.im).sin() + self.c * (self.a * p.re).cos(),
             (self.b * p.re).sin() + self.d * (self.b * p.im).cos(),
         )
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for Clifford<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        Matrix2::new(
+            -self.c * self.a * (self.a * x).sin(),
+            self.a * (self.a * y).cos(),
+            self.b * (self.b * x).cos(),
+            -self.d * self.b * (self.b * y).sin(),
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Clifford<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c", "d"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c, self.d]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-3.0, 3.0], [-3.0, 3.0], [-2.0, 2.0], [-2.0, 2.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c, d] = parameter_array(values)?;
+        Ok(Self::new(a, b, c, d))
+    }
+}
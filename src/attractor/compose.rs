@@ -0,0 +1,47 @@
+//! Implementation of the composition of two attractors.
+
+use nalgebra::{ClosedAddAssign, ClosedMulAssign, SMatrix, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{Attractor, Jacobian};
+
+/// The composition `outer ∘ inner` of two attractors, applying `inner` and then `outer` at each iteration.
+#[derive(Debug, Clone, Copy)]
+pub struct Compose<A, B> {
+    /// Attractor applied second.
+    outer: A,
+    /// Attractor applied first.
+    inner: B,
+}
+
+impl<A, B> Compose<A, B> {
+    /// Creates the composition `outer ∘ inner`.
+    #[inline]
+    pub const fn new(outer: A, inner: B) -> Self {
+        Self { outer, inner }
+    }
+}
+
+impl<T: Copy, S, A: Attractor<T, S>, B: Attractor<T, S>> Attractor<T, S> for Compose<A, B> {
+    /// Iterates both attractors at iteration `n`.
+    #[inline]
+    fn iterate_at(&self, n: usize, p: S, rng: &mut dyn RngCore, step: Option<T>) -> S {
+        let q = self.inner.iterate_at(n, p, rng, step);
+        self.outer.iterate_at(n, q, rng, step)
+    }
+}
+
+impl<T, S, A, B, const N: usize> Jacobian<T, S, N> for Compose<A, B>
+where
+    T: Float + Scalar + ClosedAddAssign + ClosedMulAssign,
+    S: Copy,
+    A: Jacobian<T, S, N>,
+    B: Attractor<T, S> + Jacobian<T, S, N>,
+{
+    /// Evaluates the Jacobian by the chain rule.
+    #[inline]
+    fn jacobian(&self, p: S) -> SMatrix<T, N, N> {
+        self.outer.jacobian(self.inner.iterate(p)) * self.inner.jacobian(p)
+    }
+}
@@ -1,9 +1,13 @@
 //! Implementation of the `DeJong` attractor.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 
-use crate::Attractor;
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
 
 /// The `DeJong` attractor defined by the equations:
 /// - `x_{n+1} = a * sin(im_n) - b * cos(x_n)`
@@ -30,10 +34,46 @@ impl<T> DeJong<T> {
 
 impl<T: Float + Copy> Attractor<T> for DeJong<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         Complex::new(
             (self.a * p.im).sin() - (self.b * p.re).cos(),
             (self.c * p.re).sin() - (self.d * p.im).cos(),
         )
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for DeJong<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        Matrix2::new(
+            self.b * (self.b * x).sin(),
+            self.a * (self.a * y).cos(),
+            self.c * (self.c * x).cos(),
+            self.d * (self.d * y).sin(),
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for DeJong<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c", "d"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c, self.d]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-3.0, 3.0]; 4])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c, d] = parameter_array(values)?;
+        Ok(Self::new(a, b, c, d))
+    }
+}
@@ -1,9 +1,13 @@
 //! Implementation of the Duffing attractor.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 
-use crate::Attractor;
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
 
 /// The Duffing attractor defined by the equations:
 /// - `x_{n+1} = y_n`
@@ -26,9 +30,40 @@ impl<T> Duffing<T> {
 
 impl<T: Float + Copy> Attractor<T> for Duffing<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         let x = p.re;
         let y = p.im;
         Complex::new(y, -self.b * x + self.a * y - y * y * y)
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for Duffing<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let y = p.im;
+        Matrix2::new(T::zero(), T::one(), -self.b, self.a - T::from(3.0).unwrap() * y * y)
+    }
+}
+
+impl<T: Float> Parametric<T> for Duffing<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[2.0, 3.0], [0.0, 0.3]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b] = parameter_array(values)?;
+        Ok(Self::new(a, b))
+    }
+}
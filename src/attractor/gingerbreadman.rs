@@ -1,10 +1,11 @@
 //! Implementation of the Gingerbreadman map.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 use std::marker::PhantomData;
 
-use crate::Attractor;
+use crate::{Attractor, Jacobian};
 
 /// The Gingerbreadman map defined by the equations:
 /// - `x_{n+1} = 1 - y_n + |x_n|`
@@ -27,9 +28,16 @@ impl<T> Gingerbreadman<T> {
 
 impl<T: Float + Copy> Attractor<T> for Gingerbreadman<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         let x = p.re;
         let y = p.im;
         Complex::new(T::one() - y + x.abs(), x)
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for Gingerbreadman<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        Matrix2::new(p.re.signum(), -T::one(), T::one(), T::zero())
+    }
+}
@@ -0,0 +1,94 @@
+//! Implementation of the Gumowski-Mira map.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Gumowski-Mira map defined by the equations:
+/// - `x_{n+1} = y_n + a * (1 - b * y_n^2) * y_n + f(x_n)`
+/// - `y_{n+1} = -x_n + f(x_{n+1})`
+///
+/// where `f(x) = mu * x + 2 * (1 - mu) * x^2 / (1 + x^2)`.
+/// The canonical parameters are `a = 0.008`, `b = 0.05`, `mu = -0.496`.
+#[derive(Debug, Clone, Copy)]
+pub struct GumowskiMira<T> {
+    /// Parameter 'a' in the Gumowski-Mira map equation.
+    a: T,
+    /// Parameter 'b' in the Gumowski-Mira map equation.
+    b: T,
+    /// Parameter 'mu' in the Gumowski-Mira map equation.
+    mu: T,
+}
+
+impl<T> GumowskiMira<T> {
+    /// Creates a new `GumowskiMira` map with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T, mu: T) -> Self {
+        Self { a, b, mu }
+    }
+}
+
+impl<T: Float> GumowskiMira<T> {
+    /// Evaluates the function `f(x) = mu * x + 2 * (1 - mu) * x^2 / (1 + x^2)`.
+    #[inline]
+    fn f(&self, x: T) -> T {
+        let x2 = x * x;
+        self.mu * x + T::from(2.0).unwrap() * (T::one() - self.mu) * x2 / (T::one() + x2)
+    }
+
+    /// Evaluates the derivative `f'(x) = mu + 4 * (1 - mu) * x / (1 + x^2)^2`.
+    #[inline]
+    fn f_prime(&self, x: T) -> T {
+        let denominator = T::one() + x * x;
+        self.mu + T::from(4.0).unwrap() * (T::one() - self.mu) * x / (denominator * denominator)
+    }
+}
+
+impl<T: Float + Copy> Attractor<T> for GumowskiMira<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        let (x, y) = (p.re, p.im);
+        let x_new = y + self.a * (T::one() - self.b * y * y) * y + self.f(x);
+        Complex::new(x_new, -x + self.f(x_new))
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for GumowskiMira<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        let x_new = self.iterate(p).re;
+        let x_wrt_x = self.f_prime(x);
+        let x_wrt_y = T::one() + self.a * (T::one() - T::from(3.0).unwrap() * self.b * y * y);
+        let slope = self.f_prime(x_new);
+        Matrix2::new(x_wrt_x, x_wrt_y, slope * x_wrt_x - T::one(), slope * x_wrt_y)
+    }
+}
+
+impl<T: Float> Parametric<T> for GumowskiMira<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "mu"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.mu]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.0, 0.01], [0.0, 0.1], [-1.0, 1.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, mu] = parameter_array(values)?;
+        Ok(Self::new(a, b, mu))
+    }
+}
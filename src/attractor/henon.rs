@@ -1,9 +1,13 @@
 //! Implementation of the `Henon` attractor.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 
-use crate::Attractor;
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
 
 /// The `Henon` attractor defined by the equations:
 /// - `x_{n+1} = 1 - a * x_n^2 + y_n`
@@ -26,7 +30,37 @@ impl<T> Henon<T> {
 
 impl<T: Float + Copy> Attractor<T> for Henon<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         Complex::new(T::one() - self.a * p.re * p.re + p.im, self.b * p.re)
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for Henon<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        Matrix2::new(-T::from(2.0).unwrap() * self.a * p.re, T::one(), self.b, T::zero())
+    }
+}
+
+impl<T: Float> Parametric<T> for Henon<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.0, 1.5], [-0.5, 0.5]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b] = parameter_array(values)?;
+        Ok(Self::new(a, b))
+    }
+}
@@ -0,0 +1,102 @@
+//! Implementation of iterated function systems.
+
+use nalgebra::Complex;
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{Affine, Attractor, random::WeightedChoice};
+
+/// An iterated function system of affine maps, rendered by the chaos game:
+/// each iteration applies one map chosen at random in proportion to its weight.
+#[derive(Debug, Clone)]
+pub struct Ifs<T> {
+    /// Affine maps chosen between at each iteration.
+    maps: Vec<Affine<T>>,
+    /// Random choice of the map applied at each iteration.
+    choice: WeightedChoice<T>,
+}
+
+impl<T: Float> Ifs<T> {
+    /// Creates a new `Ifs` from affine maps and their selection weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weights sum to zero.
+    #[must_use]
+    #[inline]
+    pub fn new(maps: Vec<(Affine<T>, T)>) -> Self {
+        let choice = WeightedChoice::new(maps.iter().map(|&(_, weight)| weight));
+        Self {
+            maps: maps.into_iter().map(|(map, _)| map).collect(),
+            choice,
+        }
+    }
+
+    /// Creates a new `Ifs` from a table of coefficients in the conventional layout.
+    ///
+    /// Each row `[a, b, c, d, e, f, p]` defines the map
+    /// - `x_{n+1} = a * x_n + b * y_n + e`
+    /// - `y_{n+1} = c * x_n + d * y_n + f`
+    ///
+    /// chosen with weight `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weights sum to zero.
+    #[must_use]
+    #[inline]
+    pub fn from_table(rows: &[[T; 7]]) -> Self {
+        Self::new(
+            rows.iter()
+                .map(|&[a, b, c, d, e, f, p]| (Affine::new(a, b, e, c, d, f), p))
+                .collect(),
+        )
+    }
+
+    /// Barnsley's fern.
+    #[must_use]
+    #[inline]
+    pub fn barnsley_fern() -> Self {
+        Self::from_table(&Self::table(&[
+            [0.0, 0.0, 0.0, 0.16, 0.0, 0.0, 0.01],
+            [0.85, 0.04, -0.04, 0.85, 0.0, 1.6, 0.85],
+            [0.2, -0.26, 0.23, 0.22, 0.0, 1.6, 0.07],
+            [-0.15, 0.28, 0.26, 0.24, 0.0, 0.44, 0.07],
+        ]))
+    }
+
+    /// Sierpinski triangle with unit side length.
+    #[must_use]
+    #[inline]
+    pub fn sierpinski() -> Self {
+        Self::from_table(&Self::table(&[
+            [0.5, 0.0, 0.0, 0.5, 0.0, 0.0, 1.0],
+            [0.5, 0.0, 0.0, 0.5, 0.5, 0.0, 1.0],
+            [0.5, 0.0, 0.0, 0.5, 0.25, 0.433_012_701_892_219_3, 1.0],
+        ]))
+    }
+
+    /// Heighway dragon curve with endpoints at zero and one.
+    #[must_use]
+    #[inline]
+    pub fn heighway_dragon() -> Self {
+        Self::from_table(&Self::table(&[
+            [0.5, -0.5, 0.5, 0.5, 0.0, 0.0, 1.0],
+            [-0.5, -0.5, 0.5, -0.5, 1.0, 0.0, 1.0],
+        ]))
+    }
+
+    /// Converts a table of `f64` coefficients.
+    #[inline]
+    fn table(rows: &[[f64; 7]]) -> Vec<[T; 7]> {
+        rows.iter().map(|row| row.map(|value| T::from(value).unwrap())).collect()
+    }
+}
+
+impl<T: Float> Attractor<T> for Ifs<T> {
+    /// Iterates the system, drawing the map choice from `rng`, as maps have no time step to take.
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        self.maps[self.choice.choose(rng)].apply(p)
+    }
+}
@@ -1,9 +1,13 @@
 //! Implementation of the Ikeda map.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 
-use crate::Attractor;
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
 
 /// The Ikeda map defined by the equations:
 /// - `t = 0.4 - 6.0 / (1 + x_n^2 + y_n^2)`
@@ -25,7 +29,7 @@ impl<T> Ikeda<T> {
 
 impl<T: Float + Copy> Attractor<T> for Ikeda<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         let x = p.re;
         let y = p.im;
 
@@ -38,3 +42,54 @@ impl<T: Float + Copy> Attractor<T> for Ikeda<T> {
         Complex::new(T::one() + self.u * (x * cos_t - y * sin_t), self.u * (x * sin_t + y * cos_t))
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for Ikeda<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let x = p.re;
+        let y = p.im;
+
+        let denominator = T::one() + x * x + y * y;
+        let t = T::from(0.4).unwrap() - T::from(6.0).unwrap() / denominator;
+        let t_rate_scale = T::from(12.0).unwrap() / (denominator * denominator);
+        let t_rate_x = t_rate_scale * x;
+        let t_rate_y = t_rate_scale * y;
+
+        let cos_t = t.cos();
+        let sin_t = t.sin();
+
+        // Rate of change of the rotated point with respect to the angle t
+        let d_re_dt = -x * sin_t - y * cos_t;
+        let d_im_dt = x * cos_t - y * sin_t;
+
+        Matrix2::new(
+            self.u * (cos_t + d_re_dt * t_rate_x),
+            self.u * (-sin_t + d_re_dt * t_rate_y),
+            self.u * (sin_t + d_im_dt * t_rate_x),
+            self.u * (cos_t + d_im_dt * t_rate_y),
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Ikeda<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["u"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.u]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.6, 1.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [u] = parameter_array(values)?;
+        Ok(Self::new(u))
+    }
+}
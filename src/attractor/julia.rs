@@ -0,0 +1,67 @@
+//! Implementation of the Julia map.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Julia map defined by the equation:
+/// - `z_{n+1} = z_n^2 + c`
+///
+/// The parameter `c` is fixed, and each orbit starts from a sampled point `z_0`.
+#[derive(Debug, Clone, Copy)]
+pub struct Julia<T> {
+    /// Parameter 'c' in the Julia equation.
+    c: Complex<T>,
+}
+
+impl<T> Julia<T> {
+    /// Creates a new `Julia` map with the specified parameter.
+    #[inline]
+    pub const fn new(c: Complex<T>) -> Self {
+        Self { c }
+    }
+}
+
+impl<T: Float> Attractor<T> for Julia<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        p * p + self.c
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for Julia<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let two = T::from(2.0).unwrap();
+        Matrix2::new(two * p.re, -two * p.im, two * p.im, two * p.re)
+    }
+}
+
+impl<T: Float> Parametric<T> for Julia<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["c_re", "c_im"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.c.re, self.c.im]
+    }
+
+    /// Ranges enclosing the Mandelbrot set, whose points give connected Julia sets.
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-2.0, 0.5], [-1.25, 1.25]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [c_re, c_im] = parameter_array(values)?;
+        Ok(Self::new(Complex::new(c_re, c_im)))
+    }
+}
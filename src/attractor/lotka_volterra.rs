@@ -0,0 +1,72 @@
+//! Implementation of the discrete Lotka-Volterra map.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The discrete Lotka-Volterra predator-prey map defined by the equations:
+/// - `x_{n+1} = a * x_n * (1 - x_n) - b * x_n * y_n`
+/// - `y_{n+1} = b * x_n * y_n`
+///
+/// where `x` is the prey and `y` the predator population.
+/// A strange attractor appears at `a = 3.6`, `b = 3.5`.
+#[derive(Debug, Clone, Copy)]
+pub struct LotkaVolterra<T> {
+    /// Prey growth rate 'a' in the Lotka-Volterra map equation.
+    a: T,
+    /// Predation rate 'b' in the Lotka-Volterra map equation.
+    b: T,
+}
+
+impl<T> LotkaVolterra<T> {
+    /// Creates a new `LotkaVolterra` map with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<T: Float + Copy> Attractor<T> for LotkaVolterra<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        let (x, y) = (p.re, p.im);
+        let predation = self.b * x * y;
+        Complex::new(self.a * x * (T::one() - x) - predation, predation)
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for LotkaVolterra<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        Matrix2::new(self.a * (T::one() - x - x) - self.b * y, -self.b * x, self.b * y, self.b * x)
+    }
+}
+
+impl<T: Float> Parametric<T> for LotkaVolterra<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[3.0, 4.0]; 2])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b] = parameter_array(values)?;
+        Ok(Self::new(a, b))
+    }
+}
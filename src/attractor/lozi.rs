@@ -0,0 +1,69 @@
+//! Implementation of the Lozi map.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Lozi map, a piecewise-linear analogue of the `Henon` map, defined by the equations:
+/// - `x_{n+1} = 1 - a * |x_n| + y_n`
+/// - `y_{n+1} = b * x_n`
+///
+/// The canonical strange attractor appears at `a = 1.7`, `b = 0.5`.
+#[derive(Debug, Clone, Copy)]
+pub struct Lozi<T> {
+    /// Parameter 'a' in the Lozi map equation.
+    a: T,
+    /// Parameter 'b' in the Lozi map equation.
+    b: T,
+}
+
+impl<T> Lozi<T> {
+    /// Creates a new `Lozi` map with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<T: Float + Copy> Attractor<T> for Lozi<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        Complex::new(T::one() - self.a * p.re.abs() + p.im, self.b * p.re)
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for Lozi<T> {
+    /// Evaluates the Jacobian, taking the one-sided derivative on the line `x = 0` where the map is not differentiable.
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        Matrix2::new(-self.a * p.re.signum(), T::one(), self.b, T::zero())
+    }
+}
+
+impl<T: Float> Parametric<T> for Lozi<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[1.0, 2.0], [0.0, 0.6]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b] = parameter_array(values)?;
+        Ok(Self::new(a, b))
+    }
+}
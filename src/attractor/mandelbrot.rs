@@ -0,0 +1,31 @@
+//! Implementation of the Mandelbrot map.
+
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{Attractor, Orbit};
+
+/// The Mandelbrot map defined by the equation:
+/// - `z_{n+1} = z_n^2 + c`
+///
+/// Each orbit carries its own parameter `c`, so sampling `c` from a generator and plotting the
+/// escaping orbits renders the Buddhabrot.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Mandelbrot;
+
+impl Mandelbrot {
+    /// Creates a new `Mandelbrot` map.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Float> Attractor<T, Orbit<T>> for Mandelbrot {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Orbit<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Orbit<T> {
+        Orbit::new(p.z * p.z + p.c, p.c)
+    }
+}
@@ -4,28 +4,203 @@
 //! a specific pattern or set of states. This module provides the core interface for
 //! implementing different types of attractors.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, SMatrix};
+use num_traits::Float;
+use rand::{RngCore, rng};
 
+use crate::Error;
+
+mod affine;
+mod alternate;
+mod arnold_cat;
+mod bedhead;
 mod chirikov;
 mod clifford;
+mod compose;
 mod de_jong;
 mod duffing;
 mod gingerbreadman;
+mod gumowski_mira;
 mod henon;
+mod ifs;
 mod ikdea;
+mod julia;
+mod lotka_volterra;
+mod lozi;
+mod mandelbrot;
+mod modulated;
+mod multibrot;
+mod perturbed;
+mod pickover;
+mod random_switch;
+mod svensson;
+mod symmetric_icon;
+mod symmetric_quilt;
 mod tinkerbell;
+mod zaslavsky;
 
+pub use affine::Affine;
+pub use alternate::Alternate;
+pub use arnold_cat::ArnoldCat;
+pub use bedhead::Bedhead;
 pub use chirikov::Chirikov;
 pub use clifford::Clifford;
+pub use compose::Compose;
 pub use de_jong::DeJong;
 pub use duffing::Duffing;
 pub use gingerbreadman::Gingerbreadman;
+pub use gumowski_mira::GumowskiMira;
 pub use henon::Henon;
+pub use ifs::Ifs;
 pub use ikdea::Ikeda;
+pub use julia::Julia;
+pub use lotka_volterra::LotkaVolterra;
+pub use lozi::Lozi;
+pub use mandelbrot::Mandelbrot;
+pub use modulated::{Modulated, Schedule};
+pub use multibrot::Multibrot;
+pub use perturbed::Perturbed;
+pub use pickover::Pickover;
+pub use random_switch::RandomSwitch;
+pub use svensson::Svensson;
+pub use symmetric_icon::SymmetricIcon;
+pub use symmetric_quilt::SymmetricQuilt;
 pub use tinkerbell::Tinkerbell;
+pub use zaslavsky::Zaslavsky;
 
 /// Trait defining the interface for attractor implementations.
-pub trait Attractor<T> {
-    /// Iterates the attractor function starting at the provided complex coordinate.
-    fn iterate(&self, p: Complex<T>) -> Complex<T>;
+///
+/// The state type `S` defaults to a point in the complex plane.
+/// Higher-dimensional attractors evolve `nalgebra` vectors instead.
+///
+/// Implementations provide [`Attractor::iterate_at`], the single entry point used by every renderer.
+/// The other methods are conveniences delegating to it, and are not meant to be overridden.
+pub trait Attractor<T, S = Complex<T>> {
+    /// Iterates the attractor function at iteration `n` of an orbit, drawing any random choices from `rng`,
+    /// and advancing attractors sampled from continuous flows by `step` instead of their own step size when set.
+    ///
+    /// Orbits are numbered from zero at the sampled starting point, including the warmup iterations.
+    /// Autonomous attractors ignore `n`, deterministic attractors ignore `rng`, and discrete maps ignore `step`,
+    /// while combinators forward all of the arguments to the attractors they wrap.
+    fn iterate_at(&self, n: usize, p: S, rng: &mut dyn RngCore, step: Option<T>) -> S;
+
+    /// Iterates the attractor function starting at the provided state.
+    ///
+    /// The state is treated as the start of an orbit, and random choices are drawn from the thread-local generator.
+    #[inline]
+    fn iterate(&self, p: S) -> S {
+        self.iterate_at(0, p, &mut rng(), None)
+    }
+
+    /// Iterates the attractor function using an explicit integration time step.
+    ///
+    /// Discrete maps have no notion of a time step and ignore `dt`,
+    /// while attractors sampled from continuous flows advance by `dt` instead of their own step size.
+    #[inline]
+    fn iterate_with_step(&self, p: S, dt: T) -> S {
+        self.iterate_at(0, p, &mut rng(), Some(dt))
+    }
+
+    /// Iterates the attractor function, drawing any random choices from `rng`.
+    ///
+    /// Deterministic attractors ignore `rng`, while stochastic attractors use it for every random choice,
+    /// so renders driven by a seeded stream are reproducible.
+    #[inline]
+    fn iterate_with_rng(&self, p: S, rng: &mut dyn RngCore) -> S {
+        self.iterate_at(0, p, rng, None)
+    }
+}
+
+impl<T, S, M: Attractor<T, S> + ?Sized> Attractor<T, S> for Box<M> {
+    #[inline]
+    fn iterate_at(&self, n: usize, p: S, rng: &mut dyn RngCore, step: Option<T>) -> S {
+        (**self).iterate_at(n, p, rng, step)
+    }
+}
+
+/// Trait exposing the analytic derivative of an attractor map over the `N` coordinates of its state.
+///
+/// The state type `S` defaults to a point in the complex plane, whose Jacobian matrix is
+/// `[[dx'/dx, dx'/dy], [dy'/dx, dy'/dy]]`.
+pub trait Jacobian<T, S = Complex<T>, const N: usize = 2> {
+    /// Evaluates the Jacobian matrix, whose entry `(i, j)` is the derivative of coordinate `i` of the image by coordinate `j`,
+    /// at the provided state.
+    fn jacobian(&self, p: S) -> SMatrix<T, N, N>;
+}
+
+impl<T, S, M: Jacobian<T, S, N> + ?Sized, const N: usize> Jacobian<T, S, N> for Box<M> {
+    #[inline]
+    fn jacobian(&self, p: S) -> SMatrix<T, N, N> {
+        (**self).jacobian(p)
+    }
+}
+
+/// Trait exposing the scalar parameters of an attractor, so that sweeps, searches and interfaces
+/// can inspect and vary any attractor without knowing its type.
+///
+/// Every method lists the parameters in the same order.
+pub trait Parametric<T> {
+    /// Names of the parameters.
+    fn parameter_names(&self) -> Vec<&str>;
+
+    /// Current values of the parameters.
+    fn parameter_values(&self) -> Vec<T>;
+
+    /// Recommended `[min, max]` range of each parameter, where interesting dynamics are commonly found.
+    ///
+    /// The ranges are a starting point for exploration rather than hard bounds.
+    fn parameter_ranges(&self) -> Vec<[T; 2]>;
+
+    /// Creates a copy of the attractor with the given parameter values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParameterCount`] if the number of values differs from the number of parameters.
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Number of parameters.
+    #[inline]
+    fn num_parameters(&self) -> usize {
+        self.parameter_names().len()
+    }
+}
+
+impl<T, M: Parametric<T>> Parametric<T> for Box<M> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        (**self).parameter_names()
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        (**self).parameter_values()
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        (**self).parameter_ranges()
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        (**self).with_parameters(values).map(Self::new)
+    }
+}
+
+/// Converts parameter values to an array of the number of parameters of an attractor.
+pub(crate) fn parameter_array<T: Copy, const N: usize>(values: &[T]) -> Result<[T; N], Error> {
+    values.try_into().map_err(|_err| Error::ParameterCount {
+        expected: N,
+        found: values.len(),
+    })
+}
+
+/// Converts recommended parameter ranges to the float type of an attractor.
+pub(crate) fn recommended_ranges<T: Float>(ranges: &[[f64; 2]]) -> Vec<[T; 2]> {
+    ranges
+        .iter()
+        .map(|&[min, max]| [T::from(min).unwrap(), T::from(max).unwrap()])
+        .collect()
 }
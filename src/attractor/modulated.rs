@@ -0,0 +1,99 @@
+//! Implementation of attractors whose parameters vary with the iteration index.
+
+use num_traits::{Float, FloatConst};
+use rand::RngCore;
+
+use crate::Attractor;
+
+/// Value of a modulated parameter as a function of the iteration index `n`.
+#[non_exhaustive]
+pub enum Schedule<T> {
+    /// `mean + amplitude * sin(2 pi n / period)`.
+    Sine {
+        /// Value about which the parameter oscillates.
+        mean: T,
+        /// Amplitude of the oscillation.
+        amplitude: T,
+        /// Number of iterations in one oscillation.
+        period: T,
+    },
+    /// Linear drift from `start` at `n = 0` to `end` at `n = length`, holding `end` afterwards.
+    Ramp {
+        /// Value at the first iteration.
+        start: T,
+        /// Value from iteration `length` onwards.
+        end: T,
+        /// Number of iterations taken to drift from `start` to `end`.
+        length: T,
+    },
+    /// Alternation between `high` for the first half of each period and `low` for the second.
+    Square {
+        /// Value during the first half of each period.
+        high: T,
+        /// Value during the second half of each period.
+        low: T,
+        /// Number of iterations in one period.
+        period: T,
+    },
+    /// User-supplied function of the iteration index.
+    Custom(Box<dyn Fn(usize) -> T + Send + Sync>),
+}
+
+impl<T: Float + FloatConst> Schedule<T> {
+    /// Evaluates the schedule at iteration `n`.
+    ///
+    /// # Panics
+    ///
+    /// This function will not panic.
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "The user function can only be borrowed through match ergonomics."
+    )]
+    #[inline]
+    pub fn value(&self, n: usize) -> T {
+        let time = T::from(n).unwrap();
+        match self {
+            &Self::Sine { mean, amplitude, period } => mean + amplitude * (T::TAU() * time / period).sin(),
+            &Self::Ramp { start, end, length } => start + (end - start) * (time / length).min(T::one()),
+            &Self::Square { high, low, period } => {
+                if (time / period).fract() < T::from(0.5).unwrap() {
+                    high
+                } else {
+                    low
+                }
+            }
+            Self::Custom(function) => function(n),
+        }
+    }
+}
+
+/// A non-autonomous attractor built from a family of attractors, whose parameter follows a schedule.
+///
+/// At iteration `n` the map applied is the member of the family at the scheduled value `s(n)`.
+/// Several parameters can be driven together by computing each from the scheduled value within the family.
+pub struct Modulated<T, A> {
+    /// Value of the driving parameter at each iteration.
+    schedule: Schedule<T>,
+    /// Constructs the attractor for a value of the driving parameter.
+    family: Box<dyn Fn(T) -> A + Send + Sync>,
+}
+
+impl<T, A> Modulated<T, A> {
+    /// Creates a new `Modulated` attractor from a schedule and a family taking the scheduled value.
+    ///
+    /// The family is called once per iteration, so it should be cheap, such as the `new` function of a built-in map.
+    #[inline]
+    pub fn new<F: Fn(T) -> A + Send + Sync + 'static>(schedule: Schedule<T>, family: F) -> Self {
+        Self {
+            schedule,
+            family: Box::new(family),
+        }
+    }
+}
+
+impl<T: Float + FloatConst, S, A: Attractor<T, S>> Attractor<T, S> for Modulated<T, A> {
+    #[inline]
+    fn iterate_at(&self, n: usize, p: S, rng: &mut dyn RngCore, step: Option<T>) -> S {
+        (self.family)(self.schedule.value(n)).iterate_at(n, p, rng, step)
+    }
+}
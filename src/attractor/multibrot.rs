@@ -0,0 +1,37 @@
+//! Implementation of the Multibrot map.
+
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{Attractor, Orbit};
+
+/// The Multibrot map defined by the equation:
+/// - `z_{n+1} = z_n^d + c`
+///
+/// Each orbit carries its own parameter `c`, and a degree of two gives the Mandelbrot map.
+#[derive(Debug, Clone, Copy)]
+pub struct Multibrot {
+    /// Degree 'd' of the power in the Multibrot equation.
+    degree: i32,
+}
+
+impl Multibrot {
+    /// Creates a new `Multibrot` map with the specified degree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is less than two.
+    #[must_use]
+    #[inline]
+    pub const fn new(degree: i32) -> Self {
+        assert!(degree >= 2, "Degree must be at least two");
+        Self { degree }
+    }
+}
+
+impl<T: Float> Attractor<T, Orbit<T>> for Multibrot {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Orbit<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Orbit<T> {
+        Orbit::new(p.z.powi(self.degree) + p.c, p.c)
+    }
+}
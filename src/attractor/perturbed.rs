@@ -0,0 +1,79 @@
+//! Implementation of attractors perturbed by additive noise.
+
+use nalgebra::{Complex, Matrix2, SVector, Scalar};
+use num_traits::{Float, FloatConst};
+use rand::RngCore;
+
+use crate::{Attractor, Jacobian, random::normal_pair};
+
+/// An attractor whose state is perturbed by additive Gaussian noise after each iteration.
+///
+/// Each coordinate receives independent noise with zero mean and the given standard deviation.
+#[derive(Debug, Clone, Copy)]
+pub struct Perturbed<T, A> {
+    /// Attractor applied before the noise is added.
+    attractor: A,
+    /// Standard deviation of the noise added to each coordinate.
+    std_dev: T,
+}
+
+impl<T, A> Perturbed<T, A> {
+    /// Creates a new `Perturbed` attractor adding noise with the specified standard deviation.
+    #[inline]
+    pub const fn new(attractor: A, std_dev: T) -> Self {
+        Self { attractor, std_dev }
+    }
+}
+
+impl<T: Float + FloatConst, A> Perturbed<T, A> {
+    /// Adds noise to a point of the plane.
+    #[inline]
+    fn perturb_plane(&self, p: Complex<T>, rng: &mut dyn RngCore) -> Complex<T> {
+        let (x, y) = normal_pair::<T>(rng);
+        p + Complex::new(x, y).scale(self.std_dev)
+    }
+
+    /// Adds noise to a vector state.
+    #[inline]
+    fn perturb_vector<const N: usize>(&self, p: SVector<T, N>, rng: &mut dyn RngCore) -> SVector<T, N>
+    where
+        T: Scalar,
+    {
+        let mut q = p;
+        for pair in q.as_mut_slice().chunks_mut(2) {
+            let noise_pair: [T; 2] = normal_pair::<T>(rng).into();
+            for (coordinate, noise) in pair.iter_mut().zip(noise_pair) {
+                *coordinate = *coordinate + noise * self.std_dev;
+            }
+        }
+        q
+    }
+}
+
+impl<T: Float + FloatConst, A: Attractor<T>> Attractor<T> for Perturbed<T, A> {
+    #[inline]
+    fn iterate_at(&self, n: usize, p: Complex<T>, rng: &mut dyn RngCore, step: Option<T>) -> Complex<T> {
+        let q = self.attractor.iterate_at(n, p, rng, step);
+        self.perturb_plane(q, rng)
+    }
+}
+
+impl<T, A, const N: usize> Attractor<T, SVector<T, N>> for Perturbed<T, A>
+where
+    T: Float + FloatConst + Scalar,
+    A: Attractor<T, SVector<T, N>>,
+{
+    #[inline]
+    fn iterate_at(&self, n: usize, p: SVector<T, N>, rng: &mut dyn RngCore, step: Option<T>) -> SVector<T, N> {
+        let q = self.attractor.iterate_at(n, p, rng, step);
+        self.perturb_vector(q, rng)
+    }
+}
+
+impl<T: Float + Scalar, A: Jacobian<T>> Jacobian<T> for Perturbed<T, A> {
+    /// Evaluates the Jacobian of the unperturbed map, as the noise is additive.
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        self.attractor.jacobian(p)
+    }
+}
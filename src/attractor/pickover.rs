@@ -0,0 +1,81 @@
+//! Implementation of the Pickover attractor.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The planar Pickover attractor of Clifford Pickover defined by the equations:
+/// - `x_{n+1} = sin(b * y_n) + c * sin(b * x_n)`
+/// - `y_{n+1} = sin(a * x_n) + d * sin(a * y_n)`
+///
+/// The canonical parameters are `a = -0.966918`, `b = 2.879879`, `c = 0.765145`, `d = 0.744728`.
+#[derive(Debug, Clone, Copy)]
+pub struct Pickover<T> {
+    /// Parameter 'a' in the Pickover attractor equation.
+    a: T,
+    /// Parameter 'b' in the Pickover attractor equation.
+    b: T,
+    /// Parameter 'c' in the Pickover attractor equation.
+    c: T,
+    /// Parameter 'd' in the Pickover attractor equation.
+    d: T,
+}
+
+impl<T> Pickover<T> {
+    /// Creates a new `Pickover` attractor with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T, c: T, d: T) -> Self {
+        Self { a, b, c, d }
+    }
+}
+
+impl<T: Float + Copy> Attractor<T> for Pickover<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        Complex::new(
+            (self.b * p.im).sin() + self.c * (self.b * p.re).sin(),
+            (self.a * p.re).sin() + self.d * (self.a * p.im).sin(),
+        )
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for Pickover<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        Matrix2::new(
+            self.c * self.b * (self.b * x).cos(),
+            self.b * (self.b * y).cos(),
+            self.a * (self.a * x).cos(),
+            self.d * self.a * (self.a * y).cos(),
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Pickover<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c", "d"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c, self.d]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-3.0, 3.0]; 4])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c, d] = parameter_array(values)?;
+        Ok(Self::new(a, b, c, d))
+    }
+}
@@ -0,0 +1,42 @@
+//! Implementation of random switching between attractors.
+
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{Attractor, random::WeightedChoice};
+
+/// Random switching between attractors: each iteration applies one attractor chosen at random
+/// in proportion to its weight, as in Parrondo's games.
+///
+/// Attractors of different types can be mixed by boxing them as trait objects.
+#[derive(Debug, Clone)]
+pub struct RandomSwitch<T, A> {
+    /// Attractors chosen between at each iteration.
+    attractors: Vec<A>,
+    /// Random choice of the attractor applied at each iteration.
+    choice: WeightedChoice<T>,
+}
+
+impl<T: Float, A> RandomSwitch<T, A> {
+    /// Creates a new `RandomSwitch` from attractors and their selection weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weights sum to zero.
+    #[must_use]
+    #[inline]
+    pub fn new(attractors: Vec<(A, T)>) -> Self {
+        let choice = WeightedChoice::new(attractors.iter().map(|&(_, weight)| weight));
+        Self {
+            attractors: attractors.into_iter().map(|(attractor, _)| attractor).collect(),
+            choice,
+        }
+    }
+}
+
+impl<T: Float, S, A: Attractor<T, S>> Attractor<T, S> for RandomSwitch<T, A> {
+    #[inline]
+    fn iterate_at(&self, n: usize, p: S, rng: &mut dyn RngCore, step: Option<T>) -> S {
+        self.attractors[self.choice.choose(rng)].iterate_at(n, p, rng, step)
+    }
+}
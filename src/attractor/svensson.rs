@@ -0,0 +1,81 @@
+//! Implementation of the Svensson attractor.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Svensson attractor of Johnny Svensson defined by the equations:
+/// - `x_{n+1} = d * sin(a * x_n) - sin(b * y_n)`
+/// - `y_{n+1} = c * cos(a * x_n) + cos(b * y_n)`
+///
+/// The canonical parameters are `a = 1.4`, `b = 1.56`, `c = 1.4`, `d = -6.56`.
+#[derive(Debug, Clone, Copy)]
+pub struct Svensson<T> {
+    /// Parameter 'a' in the Svensson attractor equation.
+    a: T,
+    /// Parameter 'b' in the Svensson attractor equation.
+    b: T,
+    /// Parameter 'c' in the Svensson attractor equation.
+    c: T,
+    /// Parameter 'd' in the Svensson attractor equation.
+    d: T,
+}
+
+impl<T> Svensson<T> {
+    /// Creates a new `Svensson` attractor with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T, c: T, d: T) -> Self {
+        Self { a, b, c, d }
+    }
+}
+
+impl<T: Float + Copy> Attractor<T> for Svensson<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        Complex::new(
+            self.d * (self.a * p.re).sin() - (self.b * p.im).sin(),
+            self.c * (self.a * p.re).cos() + (self.b * p.im).cos(),
+        )
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for Svensson<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        Matrix2::new(
+            self.d * self.a * (self.a * x).cos(),
+            -self.b * (self.b * y).cos(),
+            -self.c * self.a * (self.a * x).sin(),
+            -self.b * (self.b * y).sin(),
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Svensson<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c", "d"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c, self.d]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-3.0, 3.0], [-3.0, 3.0], [-3.0, 3.0], [-7.0, 7.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c, d] = parameter_array(values)?;
+        Ok(Self::new(a, b, c, d))
+    }
+}
@@ -0,0 +1,112 @@
+//! Implementation of the symmetric icon attractor.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The symmetric icon attractor of Field and Golubitsky defined by the equation:
+/// - `z_{n+1} = (lambda + alpha * |z_n|^2 + beta * Re(z_n^d) + i * omega) * z_n + gamma * conj(z_n)^(d - 1)`
+///
+/// The map commutes with rotations by `2 * pi / d`, and with reflection in the real axis when `omega` is zero,
+/// so its attractors have `d`-fold rotational symmetry.
+#[derive(Debug, Clone, Copy)]
+pub struct SymmetricIcon<T> {
+    /// Parameter 'lambda' in the symmetric icon equation.
+    lambda: T,
+    /// Parameter 'alpha' in the symmetric icon equation.
+    alpha: T,
+    /// Parameter 'beta' in the symmetric icon equation.
+    beta: T,
+    /// Parameter 'gamma' in the symmetric icon equation.
+    gamma: T,
+    /// Parameter 'omega' in the symmetric icon equation.
+    omega: T,
+    /// Degree of symmetry 'd' in the symmetric icon equation.
+    degree: i32,
+}
+
+impl<T> SymmetricIcon<T> {
+    /// Creates a new `SymmetricIcon` attractor with the specified parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is less than one.
+    #[inline]
+    pub const fn new(lambda: T, alpha: T, beta: T, gamma: T, omega: T, degree: i32) -> Self {
+        assert!(degree >= 1, "Degree of symmetry must be at least one");
+        Self {
+            lambda,
+            alpha,
+            beta,
+            gamma,
+            omega,
+            degree,
+        }
+    }
+}
+
+impl<T: Float> Attractor<T> for SymmetricIcon<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        let power = p.powi(self.degree - 1);
+        let scale = self.lambda + self.alpha * p.norm_sqr() + self.beta * (power * p).re;
+        Complex::new(scale, self.omega) * p + power.conj().scale(self.gamma)
+    }
+}
+
+impl<T: Float + Scalar> Jacobian<T> for SymmetricIcon<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x, y) = (p.re, p.im);
+        let two = T::from(2.0).unwrap();
+        let degree = T::from(self.degree).unwrap();
+
+        // Derivatives of the real scale factor
+        let power = p.powi(self.degree - 1);
+        let scale = self.lambda + self.alpha * p.norm_sqr() + self.beta * (power * p).re;
+        let scale_x = two * self.alpha * x + self.beta * degree * power.re;
+        let scale_y = two * self.alpha * y - self.beta * degree * power.im;
+
+        // Derivative of z^(d - 1), whose conjugate gives the derivatives of conj(z)^(d - 1)
+        let derivative = if self.degree >= 2 {
+            p.powi(self.degree - 2).scale(degree - T::one())
+        } else {
+            Complex::new(T::zero(), T::zero())
+        };
+
+        Matrix2::new(
+            scale + x * scale_x + self.gamma * derivative.re,
+            -self.omega + x * scale_y - self.gamma * derivative.im,
+            self.omega + y * scale_x - self.gamma * derivative.im,
+            scale + y * scale_y - self.gamma * derivative.re,
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for SymmetricIcon<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["lambda", "alpha", "beta", "gamma", "omega"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.lambda, self.alpha, self.beta, self.gamma, self.omega]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-2.5, 2.5], [-2.5, 2.5], [-1.0, 1.0], [-1.0, 1.0], [-0.5, 0.5]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [lambda, alpha, beta, gamma, omega] = parameter_array(values)?;
+        Ok(Self::new(lambda, alpha, beta, gamma, omega, self.degree))
+    }
+}
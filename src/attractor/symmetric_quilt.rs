@@ -0,0 +1,133 @@
+//! Implementation of the symmetric quilt attractor.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::{Float, FloatConst};
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The square symmetric quilt attractor of Field and Golubitsky defined by the equations:
+/// - `x_{n+1} = m * x_n + shift + lambda * sin(2 pi x_n) + alpha * sin(2 pi x_n) cos(2 pi y_n) + beta * sin(4 pi x_n) + gamma * sin(6 pi x_n) cos(4 pi y_n) + omega * sin(2 pi y_n)`
+/// - `y_{n+1} = m * y_n + shift + lambda * sin(2 pi y_n) + alpha * sin(2 pi y_n) cos(2 pi x_n) + beta * sin(4 pi y_n) + gamma * sin(6 pi y_n) cos(4 pi x_n) + omega * sin(2 pi x_n)`
+///
+/// both taken modulo one. The map commutes with the symmetries of the square lattice, so its
+/// attractor on the unit cell tiles the plane as a periodic quilt.
+#[derive(Debug, Clone, Copy)]
+pub struct SymmetricQuilt<T> {
+    /// Parameter 'lambda' in the symmetric quilt equations.
+    lambda: T,
+    /// Parameter 'alpha' in the symmetric quilt equations.
+    alpha: T,
+    /// Parameter 'beta' in the symmetric quilt equations.
+    beta: T,
+    /// Parameter 'gamma' in the symmetric quilt equations.
+    gamma: T,
+    /// Parameter 'omega' in the symmetric quilt equations.
+    omega: T,
+    /// Integer multiplier 'm' in the symmetric quilt equations.
+    m: T,
+    /// Parameter 'shift' in the symmetric quilt equations.
+    shift: T,
+}
+
+impl<T> SymmetricQuilt<T> {
+    /// Creates a new `SymmetricQuilt` attractor with the specified parameters.
+    #[inline]
+    pub const fn new(lambda: T, alpha: T, beta: T, gamma: T, omega: T, m: T, shift: T) -> Self {
+        Self {
+            lambda,
+            alpha,
+            beta,
+            gamma,
+            omega,
+            m,
+            shift,
+        }
+    }
+}
+
+impl<T: Float + FloatConst> SymmetricQuilt<T> {
+    /// Evaluates one coordinate of the map before reduction modulo one.
+    #[inline]
+    fn coordinate(&self, u: T, v: T) -> T {
+        let two = T::from(2.0).unwrap();
+        let (pu, pv) = (T::TAU() * u, T::TAU() * v);
+        self.m * u
+            + self.shift
+            + self.lambda * pu.sin()
+            + self.alpha * pu.sin() * pv.cos()
+            + self.beta * (two * pu).sin()
+            + self.gamma * (T::from(3.0).unwrap() * pu).sin() * (two * pv).cos()
+            + self.omega * pv.sin()
+    }
+
+    /// Evaluates the derivatives of one coordinate of the map with respect to `u` and `v`.
+    #[inline]
+    fn coordinate_derivatives(&self, u: T, v: T) -> (T, T) {
+        let two = T::from(2.0).unwrap();
+        let three = T::from(3.0).unwrap();
+        let (pu, pv) = (T::TAU() * u, T::TAU() * v);
+        let du = self.m
+            + T::TAU()
+                * (self.lambda * pu.cos()
+                    + self.alpha * pu.cos() * pv.cos()
+                    + two * self.beta * (two * pu).cos()
+                    + three * self.gamma * (three * pu).cos() * (two * pv).cos());
+        let dv = T::TAU()
+            * (-self.alpha * pu.sin() * pv.sin() - two * self.gamma * (three * pu).sin() * (two * pv).sin()
+                + self.omega * pv.cos());
+        (du, dv)
+    }
+}
+
+impl<T: Float + FloatConst> Attractor<T> for SymmetricQuilt<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        let x = self.coordinate(p.re, p.im);
+        let y = self.coordinate(p.im, p.re);
+        Complex::new(x - x.floor(), y - y.floor())
+    }
+}
+
+impl<T: Float + FloatConst + Scalar> Jacobian<T> for SymmetricQuilt<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (x_wrt_x, x_wrt_y) = self.coordinate_derivatives(p.re, p.im);
+        let (y_wrt_y, y_wrt_x) = self.coordinate_derivatives(p.im, p.re);
+        Matrix2::new(x_wrt_x, x_wrt_y, y_wrt_x, y_wrt_y)
+    }
+}
+
+impl<T: Float> Parametric<T> for SymmetricQuilt<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["lambda", "alpha", "beta", "gamma", "omega", "m", "shift"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.lambda, self.alpha, self.beta, self.gamma, self.omega, self.m, self.shift]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[
+            [-1.0, 1.0],
+            [-1.0, 1.0],
+            [-0.5, 0.5],
+            [-0.5, 0.5],
+            [-0.5, 0.5],
+            [-2.0, 2.0],
+            [0.0, 0.5],
+        ])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [lambda, alpha, beta, gamma, omega, m, shift] = parameter_array(values)?;
+        Ok(Self::new(lambda, alpha, beta, gamma, omega, m, shift))
+    }
+}
@@ -1,9 +1,13 @@
 //! Implementation of the Tinkerbell map.
 
-use nalgebra::Complex;
+use nalgebra::{Complex, Matrix2, Scalar};
 use num_traits::Float;
+use rand::RngCore;
 
-use crate::Attractor;
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
 
 /// The Tinkerbell map defined by the equations:
 /// - `x_{n+1} = x_n^2 - y_n^2 + a * x_n + b * y_n`
@@ -30,7 +34,7 @@ impl<T> Tinkerbell<T> {
 
 impl<T: Float + Copy> Attractor<T> for Tinkerbell<T> {
     #[inline]
-    fn iterate(&self, p: Complex<T>) -> Complex<T> {
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
         let x = p.re;
         let y = p.im;
         Complex::new(
@@ -39,3 +43,36 @@ impl<T: Float + Copy> Attractor<T> for Tinkerbell<T> {
         )
     }
 }
+
+impl<T: Float + Scalar> Jacobian<T> for Tinkerbell<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let two = T::from(2.0).unwrap();
+        let x = p.re;
+        let y = p.im;
+        Matrix2::new(two * x + self.a, self.b - two * y, two * y + self.c, two * x + self.d)
+    }
+}
+
+impl<T: Float> Parametric<T> for Tinkerbell<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c", "d"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c, self.d]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[-1.0, 1.0], [-1.0, 1.0], [-2.5, 2.5], [-1.0, 1.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c, d] = parameter_array(values)?;
+        Ok(Self::new(a, b, c, d))
+    }
+}
@@ -0,0 +1,93 @@
+//! Implementation of the Zaslavsky map.
+
+use nalgebra::{Complex, Matrix2, Scalar};
+use num_traits::{Float, FloatConst};
+use rand::RngCore;
+
+use crate::{
+    Attractor, Error, Jacobian, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The dissipative Zaslavsky map of a periodically kicked damped rotator, defined by the equations:
+/// - `x_{n+1} = x_n + nu * (1 + mu * y_n) + epsilon * nu * mu * cos(2 pi x_n)  (mod 1)`
+/// - `y_{n+1} = exp(-r) * (y_n + epsilon * cos(2 pi x_n))`
+///
+/// where `mu = (1 - exp(-r)) / r`, taking its limit `mu = 1` in the undamped case `r = 0`.
+/// A strange attractor filling the unit cell appears at `epsilon = 7`, `nu = 0.2`, `r = 2`.
+#[derive(Debug, Clone, Copy)]
+pub struct Zaslavsky<T> {
+    /// Kick strength 'epsilon' in the Zaslavsky map equation.
+    epsilon: T,
+    /// Frequency ratio 'nu' in the Zaslavsky map equation.
+    nu: T,
+    /// Damping 'r' in the Zaslavsky map equation.
+    r: T,
+}
+
+impl<T> Zaslavsky<T> {
+    /// Creates a new `Zaslavsky` map with the specified parameters.
+    #[inline]
+    pub const fn new(epsilon: T, nu: T, r: T) -> Self {
+        Self { epsilon, nu, r }
+    }
+}
+
+impl<T: Float> Zaslavsky<T> {
+    /// Evaluates the damping factor `exp(-r)` and the derived parameter `mu`.
+    ///
+    /// `mu` is computed with `exp_m1` to stay accurate for weak damping, and takes its limit of one without damping.
+    #[inline]
+    fn damping(&self) -> (T, T) {
+        let decay = (-self.r).exp();
+        let mu = if self.r.is_zero() {
+            T::one()
+        } else {
+            -(-self.r).exp_m1() / self.r
+        };
+        (decay, mu)
+    }
+}
+
+impl<T: Float + FloatConst> Attractor<T> for Zaslavsky<T> {
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        let (x, y) = (p.re, p.im);
+        let (decay, mu) = self.damping();
+        let kick = self.epsilon * (T::TAU() * x).cos();
+        let x_new = x + self.nu * (T::one() + mu * y) + self.nu * mu * kick;
+        Complex::new(x_new - x_new.floor(), decay * (y + kick))
+    }
+}
+
+impl<T: Float + FloatConst + Scalar> Jacobian<T> for Zaslavsky<T> {
+    #[inline]
+    fn jacobian(&self, p: Complex<T>) -> Matrix2<T> {
+        let (decay, mu) = self.damping();
+        let kick_slope = -T::TAU() * self.epsilon * (T::TAU() * p.re).sin();
+        Matrix2::new(T::one() + self.nu * mu * kick_slope, self.nu * mu, decay * kick_slope, decay)
+    }
+}
+
+impl<T: Float> Parametric<T> for Zaslavsky<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["epsilon", "nu", "r"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.epsilon, self.nu, self.r]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.0, 10.0], [0.0, 1.0], [0.0, 5.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [epsilon, nu, r] = parameter_array(values)?;
+        Ok(Self::new(epsilon, nu, r))
+    }
+}
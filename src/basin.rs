@@ -0,0 +1,451 @@
+//! Rendering of basins of attraction.
+//!
+//! Every pixel of the viewport is treated as an initial condition and iterated through a planar
+//! attractor. The long-term behaviour of each orbit is classified, producing a label image of
+//! outcomes alongside an escape-time image.
+
+use nalgebra::Complex;
+use ndarray::Array2;
+use num_traits::{Float, NumCast};
+use rand::{Rng as _, rng};
+use rayon::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    Attractor, Cancellation, Error, Progress,
+    error::{is_positive, to_report},
+    progress::default_progress,
+    random::CounterRng,
+    render::create_pixel_to_position_mapper,
+};
+
+/// Configuration settings for rendering a basin of attraction.
+///
+/// Settings are constructed with a [`BasinSettingsBuilder`], and own their components,
+/// so they can be cloned, modified and sent between threads.
+#[non_exhaustive]
+pub struct BasinSettings<T> {
+    // Scientific parameters
+    /// Attractor being analysed, shared between clones of the settings.
+    pub attractor: Arc<dyn Attractor<T> + Send + Sync>,
+
+    // Rendering parameters
+    /// Resolution of the output image [height, width].
+    pub resolution: [usize; 2],
+    /// Offset of the rendering viewport in the complex plane [real, imag].
+    pub offset: [T; 2],
+    /// Scale factor for the rendering viewport (scales imaginary axis directly, real axis is scaled by the aspect ratio set by `resolution`).
+    pub scale: T,
+
+    // Processing parameters
+    /// Seed for the random choices of stochastic attractors, making basins reproducible when set.
+    pub seed: Option<u64>,
+
+    // Simulation parameters
+    /// Maximum number of iterations per initial condition.
+    pub max_iter: usize,
+    /// Orbits reaching this distance from the origin are classified as escaping.
+    pub escape_radius: T,
+    /// Distance within which two points of an orbit are considered to coincide.
+    pub tolerance: T,
+    /// Longest cycle length classified as periodic, longer or aperiodic orbits are classified as chaotic.
+    pub max_period: usize,
+    /// Integration time step for attractors sampled from continuous flows, overriding their own step size when set.
+    pub step_size: Option<T>,
+
+    // Monitoring parameters
+    /// Observer of the rows completed, shared between clones of the settings.
+    pub progress: Arc<dyn Progress>,
+    /// Token stopping the render early when cancelled, shared between clones of the settings.
+    pub cancellation: Cancellation,
+}
+
+impl<T: Clone> Clone for BasinSettings<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            attractor: Arc::clone(&self.attractor),
+            resolution: self.resolution,
+            offset: self.offset.clone(),
+            scale: self.scale.clone(),
+            seed: self.seed,
+            max_iter: self.max_iter,
+            escape_radius: self.escape_radius.clone(),
+            tolerance: self.tolerance.clone(),
+            max_period: self.max_period,
+            step_size: self.step_size.clone(),
+            progress: Arc::clone(&self.progress),
+            cancellation: self.cancellation.clone(),
+        }
+    }
+}
+
+impl<T: Float + 'static> BasinSettings<T> {
+    /// Starts building settings for the basins of the given attractor, with the defaults of [`BasinSettingsBuilder::new`].
+    #[must_use]
+    #[inline]
+    pub fn builder<A: Attractor<T> + Send + Sync + 'static>(attractor: A) -> BasinSettingsBuilder<T> {
+        BasinSettingsBuilder::new(attractor)
+    }
+}
+
+impl<T: Float> BasinSettings<T> {
+    /// Checks that the settings describe a basin which can be rendered as requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first problem found:
+    ///
+    /// - [`Error::ZeroResolution`] if the image has no pixels.
+    /// - [`Error::InvalidScale`] or [`Error::InvalidOffset`] if the viewport is degenerate.
+    /// - [`Error::InvalidStepSize`], [`Error::InvalidEscapeRadius`] or [`Error::InvalidTolerance`]
+    ///   if the simulation parameters are out of range.
+    #[inline]
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.resolution.contains(&0) {
+            return Err(Error::ZeroResolution(self.resolution));
+        }
+        if !is_positive(self.scale) {
+            return Err(Error::InvalidScale(to_report(self.scale)));
+        }
+        if !self.offset.iter().all(|component| component.is_finite()) {
+            return Err(Error::InvalidOffset(self.offset.map(to_report)));
+        }
+        if let Some(step_size) = self.step_size
+            && !is_positive(step_size)
+        {
+            return Err(Error::InvalidStepSize(to_report(step_size)));
+        }
+        if !is_positive(self.escape_radius) {
+            return Err(Error::InvalidEscapeRadius(to_report(self.escape_radius)));
+        }
+        if !is_positive(self.tolerance) {
+            return Err(Error::InvalidTolerance(to_report(self.tolerance)));
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`BasinSettings`], starting from defaults suitable for a quick preview.
+pub struct BasinSettingsBuilder<T> {
+    /// Settings built so far.
+    settings: BasinSettings<T>,
+}
+
+impl<T: Float + 'static> BasinSettingsBuilder<T> {
+    /// Creates a builder owning the given attractor.
+    ///
+    /// The defaults are a resolution of 1024 x 1024 over a viewport of height 4 centred on the origin, with
+    /// orbits of up to 1000 iterations escaping beyond a radius of 100, points coinciding within `1e-6`, and cycles
+    /// of up to 32 points classified as periodic. Progress is drawn on the terminal when the `indicatif` feature is
+    /// enabled, and otherwise ignored.
+    #[must_use]
+    #[inline]
+    pub fn new<A: Attractor<T> + Send + Sync + 'static>(attractor: A) -> Self {
+        Self::shared(Arc::new(attractor))
+    }
+
+    /// Creates a builder sharing an attractor with other settings, with the same defaults as [`BasinSettingsBuilder::new`].
+    ///
+    /// # Panics
+    ///
+    /// This function will not panic.
+    #[must_use]
+    #[inline]
+    pub fn shared(attractor: Arc<dyn Attractor<T> + Send + Sync>) -> Self {
+        Self {
+            settings: BasinSettings {
+                attractor,
+                resolution: [1024, 1024],
+                offset: [T::zero(), T::zero()],
+                scale: T::from(4.0).unwrap(),
+                seed: None,
+                max_iter: 1000,
+                escape_radius: T::from(100.0).unwrap(),
+                tolerance: T::from(1.0e-6).unwrap(),
+                max_period: 32,
+                step_size: None,
+                progress: default_progress(),
+                cancellation: Cancellation::new(),
+            },
+        }
+    }
+}
+
+impl<T: Float> BasinSettingsBuilder<T> {
+    /// Sets the resolution of the output image [height, width].
+    #[must_use]
+    #[inline]
+    pub const fn resolution(mut self, resolution: [usize; 2]) -> Self {
+        self.settings.resolution = resolution;
+        self
+    }
+
+    /// Sets the offset of the rendering viewport in the complex plane [real, imag].
+    #[must_use]
+    #[inline]
+    pub const fn offset(mut self, offset: [T; 2]) -> Self {
+        self.settings.offset = offset;
+        self
+    }
+
+    /// Sets the height of the rendering viewport.
+    #[must_use]
+    #[inline]
+    pub const fn scale(mut self, scale: T) -> Self {
+        self.settings.scale = scale;
+        self
+    }
+
+    /// Sets the seed for the random choices of stochastic attractors, making basins reproducible.
+    #[must_use]
+    #[inline]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.settings.seed = Some(seed);
+        self
+    }
+
+    /// Sets the maximum number of iterations per initial condition.
+    #[must_use]
+    #[inline]
+    pub const fn max_iter(mut self, max_iter: usize) -> Self {
+        self.settings.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the distance from the origin beyond which orbits are classified as escaping.
+    #[must_use]
+    #[inline]
+    pub const fn escape_radius(mut self, escape_radius: T) -> Self {
+        self.settings.escape_radius = escape_radius;
+        self
+    }
+
+    /// Sets the distance within which two points of an orbit are considered to coincide.
+    #[must_use]
+    #[inline]
+    pub const fn tolerance(mut self, tolerance: T) -> Self {
+        self.settings.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the longest cycle length classified as periodic.
+    #[must_use]
+    #[inline]
+    pub const fn max_period(mut self, max_period: usize) -> Self {
+        self.settings.max_period = max_period;
+        self
+    }
+
+    /// Sets the integration time step, overriding the step size of attractors sampled from continuous flows.
+    #[must_use]
+    #[inline]
+    pub const fn step_size(mut self, step_size: T) -> Self {
+        self.settings.step_size = Some(step_size);
+        self
+    }
+
+    /// Sets the observer of the rows completed, owned by the settings.
+    #[must_use]
+    #[inline]
+    pub fn progress<P: Progress + 'static>(self, progress: P) -> Self {
+        self.shared_progress(Arc::new(progress))
+    }
+
+    /// Sets the observer of the rows completed, shared with other settings.
+    #[must_use]
+    #[inline]
+    pub fn shared_progress(mut self, progress: Arc<dyn Progress>) -> Self {
+        self.settings.progress = progress;
+        self
+    }
+
+    /// Sets the token stopping the render early when cancelled, keeping a clone of the token to cancel it.
+    #[must_use]
+    #[inline]
+    pub fn cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.settings.cancellation = cancellation;
+        self
+    }
+
+    /// Builds the settings, checking them with [`BasinSettings::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the problem reported by [`BasinSettings::validate`] if the settings are invalid.
+    #[inline]
+    pub fn build(self) -> Result<BasinSettings<T>, Error> {
+        self.settings.validate()?;
+        Ok(self.settings)
+    }
+}
+
+/// Long-term behaviour of an orbit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Outcome {
+    /// The orbit escaped to infinity.
+    Escaped,
+    /// The orbit converged to the fixed point with the given index.
+    FixedPoint(usize),
+    /// The orbit converged to a cycle with the given period.
+    Periodic(usize),
+    /// The orbit remained bounded without settling onto a short cycle.
+    Chaotic,
+    /// The orbit was not iterated, as the render was cancelled first.
+    Unclassified,
+}
+
+/// Classified basins of attraction.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Basin<T> {
+    /// Outcome of the orbit started from each pixel.
+    pub labels: Array2<Outcome>,
+    /// Iteration at which the orbit started from each pixel escaped, `max_iter` if it never escaped,
+    /// or zero if it was left unclassified.
+    pub escape_times: Array2<usize>,
+    /// Distinct fixed points found, indexed by `Outcome::FixedPoint`.
+    pub fixed_points: Vec<Complex<T>>,
+}
+
+impl<T> Basin<T> {
+    /// Whether every orbit was classified, which is only false if the render was cancelled.
+    #[must_use]
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        !self.labels.iter().any(|&label| label == Outcome::Unclassified)
+    }
+}
+
+/// Outcome of a single orbit before fixed points are assigned indices.
+#[derive(Debug, Clone, Copy)]
+enum Classification<T> {
+    /// The orbit escaped at the given iteration.
+    Escaped(usize),
+    /// The orbit converged to the given fixed point.
+    FixedPoint(Complex<T>),
+    /// The orbit converged to a cycle with the given period.
+    Periodic(usize),
+    /// The orbit remained bounded without settling onto a short cycle.
+    Chaotic,
+    /// The orbit was not iterated.
+    Unclassified,
+}
+
+/// Multi-threaded rendering of the basins of attraction of a planar attractor.
+///
+/// Each row draws the random choices of stochastic attractors from its own stream of `settings.seed`,
+/// so seeded basins are reproducible regardless of how the rows are scheduled.
+/// If `settings.cancellation` is cancelled, the rows which were not rendered are labelled [`Outcome::Unclassified`],
+/// which [`Basin::is_complete`] reports.
+///
+/// # Panics
+///
+/// This function will not panic.
+#[inline]
+pub fn render_basin<T>(settings: &BasinSettings<T>) -> Basin<T>
+where
+    T: Float + NumCast + Send + Sync,
+{
+    let [height, width] = settings.resolution;
+    let offset = Complex::new(settings.offset[0], settings.offset[1]);
+    let mapper = create_pixel_to_position_mapper(offset, settings.scale, settings.resolution);
+    settings.progress.start(u64::try_from(height).unwrap(), 0, "rows");
+    let seed = settings.seed.unwrap_or_else(|| rng().random::<u64>());
+
+    // Parallelize the row rendering (each row is single-threaded).
+    let rows: Vec<Vec<Classification<T>>> = (0..height)
+        .into_par_iter()
+        .map(|row| {
+            if settings.cancellation.is_cancelled() {
+                return vec![Classification::Unclassified; width];
+            }
+            let mut row_rng = CounterRng::new(seed, u64::try_from(row).unwrap());
+            let classifications = (0..width)
+                .map(|col| classify(settings, mapper([row, col]), &mut row_rng))
+                .collect();
+            settings.progress.advance(1);
+            classifications
+        })
+        .collect();
+    settings.progress.finish();
+
+    // Assign indices to distinct fixed points
+    let mut fixed_points: Vec<Complex<T>> = Vec::new();
+    let mut labels = Array2::from_elem(settings.resolution, Outcome::Chaotic);
+    let mut escape_times = Array2::from_elem(settings.resolution, settings.max_iter);
+    for (row, classifications) in rows.iter().enumerate() {
+        for (col, classification) in classifications.iter().enumerate() {
+            labels[[row, col]] = match *classification {
+                Classification::Escaped(n) => {
+                    escape_times[[row, col]] = n;
+                    Outcome::Escaped
+                }
+                Classification::FixedPoint(p) => {
+                    let index = fixed_points
+                        .iter()
+                        .position(|q| (p - *q).norm_sqr() < settings.tolerance * settings.tolerance)
+                        .unwrap_or_else(|| {
+                            fixed_points.push(p);
+                            fixed_points.len() - 1
+                        });
+                    Outcome::FixedPoint(index)
+                }
+                Classification::Periodic(period) => Outcome::Periodic(period),
+                Classification::Chaotic => Outcome::Chaotic,
+                Classification::Unclassified => {
+                    escape_times[[row, col]] = 0;
+                    Outcome::Unclassified
+                }
+            };
+        }
+    }
+
+    Basin {
+        labels,
+        escape_times,
+        fixed_points,
+    }
+}
+
+/// Iterates a single initial condition and classifies its long-term behaviour, drawing any random choices from `rng`.
+#[inline]
+fn classify<T: Float>(settings: &BasinSettings<T>, start: Complex<T>, rng: &mut CounterRng) -> Classification<T> {
+    let attractor = settings.attractor.as_ref();
+    let mut next = |n, p| attractor.iterate_at(n, p, rng, settings.step_size);
+    let escaped = |p: Complex<T>| {
+        let radius = p.re.hypot(p.im);
+        radius.is_nan() || radius >= settings.escape_radius
+    };
+
+    // Iterate until escape, keeping the tail of the orbit for cycle detection
+    let mut pos = start;
+    let mut tail = Vec::with_capacity(settings.max_period + 1);
+    for n in 0..settings.max_iter {
+        pos = next(n, pos);
+        if escaped(pos) {
+            return Classification::Escaped(n);
+        }
+        if n + settings.max_period + 1 >= settings.max_iter {
+            tail.push(pos);
+        }
+    }
+
+    // Find the shortest period for which the orbit returns to its final point
+    let Some(last) = tail.last().copied() else {
+        return Classification::Chaotic;
+    };
+    let tolerance_sqr = settings.tolerance * settings.tolerance;
+    let period = tail
+        .iter()
+        .rev()
+        .skip(1)
+        .position(|p| (*p - last).norm_sqr() < tolerance_sqr)
+        .map(|index| index + 1);
+    match period {
+        Some(1) => Classification::FixedPoint(last),
+        Some(cycle) => Classification::Periodic(cycle),
+        None => Classification::Chaotic,
+    }
+}
@@ -0,0 +1,130 @@
+//! Rendering of bifurcation diagrams.
+//!
+//! A bifurcation diagram sweeps one attractor parameter along the horizontal axis of the image
+//! and plots the post-warmup values of one state coordinate along the vertical axis, accumulating
+//! the visits of each pixel into a density.
+
+use nalgebra::Complex;
+use ndarray::{Array2, Axis};
+use num_traits::{Float, FloatConst, FromPrimitive, NumCast};
+use rand::{
+    Rng as _,
+    distr::{Distribution, StandardUniform, uniform::SampleUniform},
+    rng,
+};
+use rayon::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    Attractor, Count, Error, Projection, Settings, State,
+    error::{check_range, is_positive, to_report},
+    render::render_density,
+};
+
+/// Attractor constructor taking the value of the swept parameter.
+pub type Family<T, S> = dyn Fn(T) -> Box<dyn Attractor<T, S> + Send + Sync> + Send + Sync;
+
+/// Parameter sweep of a bifurcation diagram.
+///
+/// The state type `S` defaults to a point in the complex plane.
+#[expect(clippy::exhaustive_structs, reason = "Sweep struct is expected to be constructed directly.")]
+pub struct Sweep<T, S = Complex<T>> {
+    /// Constructs the attractor for each value of the swept parameter.
+    pub family: Box<Family<T, S>>,
+    /// Extracts the plotted coordinate from a state.
+    pub observable: Arc<dyn Fn(&S) -> T + Send + Sync>,
+    /// Range of the swept parameter across the image, from the left to the right column [min, max].
+    pub parameter_range: [T; 2],
+    /// Range of the observed coordinate across the image, from the bottom to the top row [min, max].
+    pub value_range: [T; 2],
+}
+
+impl<T: Float, S> Sweep<T, S> {
+    /// Checks that the sweep spans finite ranges which can be mapped onto the image.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRange`] if either range is not finite or has its minimum above its maximum,
+    /// or if the value range is empty, as its rows would have no height.
+    #[inline]
+    pub fn validate(&self) -> Result<(), Error> {
+        check_range(self.parameter_range)?;
+        check_range(self.value_range)?;
+        let [min_value, max_value] = self.value_range;
+        if !is_positive(max_value - min_value) {
+            return Err(Error::InvalidRange([to_report(min_value), to_report(max_value)]));
+        }
+        Ok(())
+    }
+}
+
+/// Projection of a state onto its observed coordinate, along the imaginary axis.
+struct Observation<T, S>(Arc<dyn Fn(&S) -> T + Send + Sync>);
+
+impl<T: Float, S> Projection<T, S> for Observation<T, S> {
+    #[inline]
+    fn project(&self, p: &S) -> Option<Complex<T>> {
+        Some(Complex::new(T::zero(), (self.0)(p)))
+    }
+}
+
+/// Multi-threaded rendering of a bifurcation diagram, counting visits with the count type `C`.
+///
+/// Each column renders `settings.num_samples` samples of the member of the family at its parameter value,
+/// split between `settings.num_groups` groups and accumulated with `settings.accumulation`, exactly as [`render`](crate::render)
+/// would. The attractor, projection, offset and scale of `settings` are replaced by those of each column, and the
+/// returned density has the resolution of `settings`, with the largest observed values in the top row.
+/// Counts saturate at the maximum value of `C` instead of overflowing.
+/// If `settings.cancellation` is cancelled, the samples which were not rendered are left out.
+///
+/// # Errors
+///
+/// Returns the problem reported by [`Settings::validate`] or [`Sweep::validate`] if the settings or sweep are invalid.
+///
+/// # Panics
+///
+/// This function will not panic.
+#[inline]
+pub fn render_bifurcation<C, T, S>(settings: &Settings<T, S>, sweep: &Sweep<T, S>) -> Result<Array2<C>, Error>
+where
+    C: Count,
+    T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync + 'static,
+    S: State<T> + 'static,
+    StandardUniform: Distribution<T>,
+{
+    settings.validate()?;
+    sweep.validate()?;
+    let [height, width] = settings.resolution;
+    let [min_parameter, max_parameter] = sweep.parameter_range;
+    let [min_value, max_value] = sweep.value_range;
+    settings
+        .progress
+        .start(u64::try_from(settings.num_samples * width).unwrap(), 0, "samples");
+
+    // Each column is a single pixel wide, with its viewport spanning the observed range
+    let mut template = settings.clone();
+    template.projection = Arc::new(Observation(Arc::clone(&sweep.observable)));
+    template.resolution = [height, 1];
+    template.offset = [T::zero(), (min_value + max_value) / T::from(2.0).unwrap()];
+    template.scale = max_value - min_value;
+
+    // Each column draws from its own block of random streams, so the result does not depend on scheduling.
+    let seed = settings.seed.unwrap_or_else(|| rng().random::<u64>());
+    let columns: Vec<Array2<C>> = (0..width)
+        .into_par_iter()
+        .map(|column| {
+            let fraction = T::from(column).unwrap() / T::from(width - 1).unwrap().max(T::one());
+            let mut column_settings = template.clone();
+            column_settings.attractor = Arc::from((sweep.family)(min_parameter + (max_parameter - min_parameter) * fraction));
+            let first_stream = u64::try_from(column * settings.num_groups).unwrap();
+            render_density(&column_settings, seed, first_stream, settings.num_samples)
+        })
+        .collect();
+    settings.progress.finish();
+
+    let mut total_counts = Array2::zeros([height, width]);
+    for (mut target, counts) in total_counts.axis_iter_mut(Axis(1)).zip(&columns) {
+        target.assign(&counts.column(0));
+    }
+    Ok(total_counts)
+}
@@ -0,0 +1,273 @@
+//! Multi-channel accumulation of attractor orbits.
+//!
+//! Alongside the visit count of each pixel, a render can accumulate additional per-pixel channels
+//! describing the orbits passing through it, such as how far and in which direction they move.
+//! Downstream colouring can then, for example, map hue to the mean heading and brightness to the density.
+
+use nalgebra::Complex;
+use ndarray::{Array2, Zip};
+use num_traits::{Float, FloatConst, FromPrimitive, NumCast};
+use rand::{
+    Rng as _,
+    distr::{Distribution, StandardUniform, uniform::SampleUniform},
+    rng,
+};
+
+use crate::{
+    Count, Settings, State,
+    count::{saturated_pixels, saturating_sum},
+    render::{Accumulator, render_batch},
+};
+
+/// Quantity averaged over the orbit visits to each pixel.
+///
+/// Step lengths and headings are measured in the image plane, between consecutive projected states.
+#[non_exhaustive]
+pub enum Channel<T, S = Complex<T>> {
+    /// Mean distance `|p_{n+1} - p_n|` moved by the orbit in one step, over the visits reached by a step.
+    StepLength,
+    /// Mean direction of motion, as an angle in the range (-pi, pi].
+    Heading,
+    /// Mean index of the iteration after the warmup.
+    Iteration,
+    /// Mean of a user-supplied scalar function of the state.
+    Scalar(Box<dyn Fn(&S) -> T + Sync>),
+}
+
+impl<T, S> Channel<T, S> {
+    /// Location of the sums of the channel, starting at index `first`.
+    const fn layout(&self, first: usize) -> Layout {
+        match *self {
+            Self::StepLength => Layout::Ratio(first, first + 1),
+            Self::Heading => Layout::Direction(first, first + 1),
+            Self::Iteration | Self::Scalar(_) => Layout::Scalar(first),
+        }
+    }
+}
+
+impl<T: Float + FromPrimitive, S> Channel<T, S> {
+    /// Adds an orbit visit to the sums of the channel, given the step taken to reach it, if any.
+    ///
+    /// Visits without a previous state, and for headings visits without motion, leave the sums unchanged.
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "The user function can only be borrowed through match ergonomics."
+    )]
+    fn accumulate(
+        &self,
+        sums: &mut [Array2<T>],
+        layout: Layout,
+        pixel: [usize; 2],
+        state: &S,
+        step: Option<Complex<T>>,
+        iteration: usize,
+    ) {
+        let first = layout.first();
+        match self {
+            Self::StepLength => {
+                if let Some(delta) = step {
+                    sums[first][pixel] = sums[first][pixel] + delta.norm();
+                    sums[first + 1][pixel] = sums[first + 1][pixel] + T::one();
+                }
+            }
+            Self::Heading => {
+                if let Some(delta) = step {
+                    let length = delta.norm();
+                    if length > T::zero() {
+                        sums[first][pixel] = sums[first][pixel] + delta.re / length;
+                        sums[first + 1][pixel] = sums[first + 1][pixel] + delta.im / length;
+                    }
+                }
+            }
+            Self::Iteration => sums[first][pixel] = sums[first][pixel] + T::from_usize(iteration).unwrap(),
+            Self::Scalar(function) => sums[first][pixel] = sums[first][pixel] + function(state),
+        }
+    }
+}
+
+/// Location of the per-pixel sums of a channel within the layers.
+#[derive(Debug, Clone, Copy)]
+enum Layout {
+    /// Sum of scalar values over every visit, at the given index.
+    Scalar(usize),
+    /// Sum of scalar values, and the number of visits contributing to it, at the given indices.
+    Ratio(usize, usize),
+    /// Sum of unit vectors, with the real and imaginary parts at the given indices.
+    Direction(usize, usize),
+}
+
+impl Layout {
+    /// Index of the first sum.
+    const fn first(self) -> usize {
+        match self {
+            Self::Scalar(i) | Self::Ratio(i, _) | Self::Direction(i, _) => i,
+        }
+    }
+
+    /// Number of sums.
+    const fn len(self) -> usize {
+        match self {
+            Self::Scalar(_) => 1,
+            Self::Ratio(..) | Self::Direction(..) => 2,
+        }
+    }
+}
+
+/// Density of a render, counted with the count type `C`, together with the per-pixel sums of each requested channel.
+#[derive(Debug, Clone)]
+pub struct Layers<T, C = u32> {
+    /// Visit count of each pixel.
+    counts: Array2<C>,
+    /// Location of the sums of each channel, in the requested order.
+    layout: Vec<Layout>,
+    /// Per-pixel sums.
+    sums: Vec<Array2<T>>,
+}
+
+impl<T: Float, C: Count> Layers<T, C> {
+    /// Creates empty layers for the given channels.
+    #[inline]
+    fn new<S>(resolution: [usize; 2], channels: &[Channel<T, S>]) -> Self {
+        let mut num_sums = 0;
+        let layout = channels
+            .iter()
+            .map(|channel| {
+                let layout = channel.layout(num_sums);
+                num_sums += layout.len();
+                layout
+            })
+            .collect();
+        Self {
+            counts: Array2::zeros(resolution),
+            layout,
+            sums: vec![Array2::from_elem(resolution, T::zero()); num_sums],
+        }
+    }
+
+    /// Visit count of each pixel.
+    #[must_use]
+    #[inline]
+    pub const fn counts(&self) -> &Array2<C> {
+        &self.counts
+    }
+
+    /// Number of pixels whose counts have saturated, so some of their visits were lost.
+    ///
+    /// A non-zero result means the render should be repeated with a wider count type.
+    #[must_use]
+    #[inline]
+    pub fn saturated_pixels(&self) -> usize {
+        saturated_pixels(&self.counts)
+    }
+
+    /// Number of accumulated channels.
+    #[must_use]
+    #[inline]
+    pub const fn num_channels(&self) -> usize {
+        self.layout.len()
+    }
+
+    /// Mean value of a channel at each pixel, or zero where no orbit visited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the number of channels.
+    #[must_use]
+    #[inline]
+    pub fn mean(&self, index: usize) -> Array2<T> {
+        match self.layout[index] {
+            Layout::Scalar(i) => Zip::from(&self.sums[i]).and(&self.counts).map_collect(|&value, &count| {
+                if count == C::zero() {
+                    T::zero()
+                } else {
+                    value / T::from(count).unwrap()
+                }
+            }),
+            Layout::Ratio(i, j) => Zip::from(&self.sums[i])
+                .and(&self.sums[j])
+                .map_collect(|&value, &visits| if visits == T::zero() { T::zero() } else { value / visits }),
+            Layout::Direction(i, j) => Zip::from(&self.sums[i]).and(&self.sums[j]).map_collect(|&re, &im| {
+                if re == T::zero() && im == T::zero() {
+                    T::zero()
+                } else {
+                    im.atan2(re)
+                }
+            }),
+        }
+    }
+
+    /// Consumes the layers, returning the visit count of each pixel.
+    #[must_use]
+    #[inline]
+    pub fn into_counts(self) -> Array2<C> {
+        self.counts
+    }
+}
+
+/// Accumulates the layers for a set of channel definitions.
+struct LayerAccumulator<'c, T, S, C> {
+    /// Channel definitions, in the order of the layer layout.
+    channels: &'c [Channel<T, S>],
+    /// Accumulated layers.
+    layers: Layers<T, C>,
+}
+
+impl<T, S, C> Accumulator<T, S> for LayerAccumulator<'_, T, S, C>
+where
+    T: Float + FromPrimitive + Send + Sync,
+    C: Count,
+{
+    #[inline]
+    fn record(&mut self, pixel: [usize; 2], state: &S, position: Complex<T>, previous: Option<Complex<T>>, iteration: usize) {
+        let layers = &mut self.layers;
+        layers.counts[pixel] = layers.counts[pixel].increment();
+
+        let step = previous.map(|q| position - q);
+        for (channel, &layout) in self.channels.iter().zip(&layers.layout) {
+            channel.accumulate(&mut layers.sums, layout, pixel, state, step, iteration);
+        }
+    }
+
+    #[inline]
+    fn combine(&mut self, other: Self) {
+        saturating_sum(&mut self.layers.counts, &other.layers.counts);
+        for (sum, other_sum) in self.layers.sums.iter_mut().zip(&other.layers.sums) {
+            Zip::from(sum)
+                .and(other_sum)
+                .for_each(|value, &other_value| *value = *value + other_value);
+        }
+    }
+}
+
+/// Multi-threaded rendering of the attractor, accumulating the requested channels alongside the density.
+///
+/// The counts of the returned layers are identical to those returned by [`render_counts`](crate::render_counts) with the
+/// same settings and count type, and saturate at the maximum value of `C`.
+/// If `settings.cancellation` is cancelled, the layers of the samples completed so far are returned.
+/// The `Shared` accumulation strategy falls back to `PerThread`, as channel sums cannot be accumulated atomically.
+///
+/// # Panics
+///
+/// Panics if the number of groups is zero. Use [`Settings::validate`] to check the settings first.
+#[inline]
+pub fn render_channels<C, T, S>(settings: &Settings<T, S>, channels: &[Channel<T, S>]) -> Layers<T, C>
+where
+    C: Count,
+    T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
+    StandardUniform: Distribution<T>,
+{
+    settings
+        .progress
+        .start(u64::try_from(settings.num_samples).unwrap(), 0, "samples");
+
+    // Each group draws from its own random stream, so the result does not depend on scheduling.
+    let seed = settings.seed.unwrap_or_else(|| rng().random::<u64>());
+    let accumulator = render_batch(settings, seed, 0, settings.num_samples, &|| LayerAccumulator {
+        channels,
+        layers: Layers::new(settings.resolution, channels),
+    });
+    settings.progress.finish();
+
+    accumulator.layers
+}
@@ -0,0 +1,343 @@
+//! Resumable rendering with density checkpoint files.
+//!
+//! Long renders accumulate their density in batches, writing a checkpoint to disk after each
+//! batch so an interrupted render can be resumed. Checkpoints produced on several machines with
+//! the same settings (but different seeds) can be merged by summing their densities.
+//!
+//! # File format
+//!
+//! All integers are stored little-endian:
+//!
+//! | Offset | Size                   | Content                                                   |
+//! |--------|------------------------|-----------------------------------------------------------|
+//! | 0      | 8                      | Magic bytes `ATTRACT\0`                                   |
+//! | 8      | 4                      | Format version (`u32`, currently `2`)                     |
+//! | 12     | 4                      | Count width in bytes (`u32`, `4` or `8`)                  |
+//! | 16     | 8                      | Settings hash (`u64`)                                     |
+//! | 24     | 8                      | Image height (`u64`)                                      |
+//! | 32     | 8                      | Image width (`u64`)                                       |
+//! | 40     | 8                      | Number of accumulated samples (`u64`)                     |
+//! | 48     | width * height * count | Pixel counts (`u32` or `u64`) in row-major order          |
+//!
+//! Counts saturate at the maximum value of their type when batches or checkpoints are summed.
+//! Checkpoints of `u32` counts may be loaded as `u64` counts, but not the other way round.
+//!
+//! The settings hash is the 64-bit FNV-1a hash of the resolution, viewport, iteration counts,
+//! step size and escape condition of the render. The attractor, generator, projection and seed are not included, so
+//! it is the caller's responsibility to only merge checkpoints of the same system.
+
+#![expect(
+    clippy::little_endian_bytes,
+    reason = "The checkpoint file format is defined as little-endian."
+)]
+
+use ndarray::Array2;
+use num_traits::{Float, FloatConst, FromPrimitive, NumCast};
+use rand::{
+    Rng as _,
+    distr::{Distribution, StandardUniform, uniform::SampleUniform},
+    rng,
+};
+use std::{
+    fs::{File, rename},
+    io::{BufReader, BufWriter, Error, ErrorKind, IntoInnerError, Read, Result, Write},
+    path::Path,
+};
+
+use crate::{
+    Count, Settings, State,
+    count::{saturated_pixels, saturating_sum},
+    render::render_density,
+};
+
+/// Magic bytes identifying a checkpoint file.
+const MAGIC: [u8; 8] = *b"ATTRACT\0";
+
+/// Version of the checkpoint file format.
+const VERSION: u32 = 2;
+
+/// Size of the header preceding the pixel counts, in bytes.
+const HEADER_BYTES: u64 = 48;
+
+/// FNV-1a offset basis.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a prime.
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Accumulated density of a render in progress, counting visits with the count type `C`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Checkpoint<C = u32> {
+    /// Hash of the settings which produced the density.
+    pub settings_hash: u64,
+    /// Number of samples accumulated into the density.
+    pub num_samples: u64,
+    /// Visit count of each pixel.
+    pub counts: Array2<C>,
+}
+
+impl<C: Count> Checkpoint<C> {
+    /// Creates an empty `Checkpoint` for the given settings.
+    #[must_use]
+    #[inline]
+    pub fn new<T: Float, S>(settings: &Settings<T, S>) -> Self {
+        Self {
+            settings_hash: settings_hash(settings),
+            num_samples: 0,
+            counts: Array2::zeros(settings.resolution),
+        }
+    }
+
+    /// Adds the density of another checkpoint to this one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoints were produced with different settings or resolutions.
+    #[inline]
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.settings_hash != other.settings_hash || self.counts.dim() != other.counts.dim() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot merge checkpoints produced with different settings",
+            ));
+        }
+        self.num_samples += other.num_samples;
+        saturating_sum(&mut self.counts, &other.counts);
+        Ok(())
+    }
+
+    /// Number of pixels whose counts have saturated, so some of their visits were lost.
+    ///
+    /// A non-zero result means the render should be repeated with a wider count type.
+    #[must_use]
+    #[inline]
+    pub fn saturated_pixels(&self) -> usize {
+        saturated_pixels(&self.counts)
+    }
+
+    /// Writes the checkpoint to a file.
+    ///
+    /// The data is first written to a temporary file alongside the target, named by appending `.partial` to it,
+    /// which then replaces the target, so an interruption never leaves a partially written checkpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    #[inline]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut temporary = path.as_ref().as_os_str().to_owned();
+        temporary.push(".partial");
+        let mut writer = BufWriter::new(File::create(&temporary)?);
+        self.write(&mut writer)?;
+        writer.into_inner().map_err(IntoInnerError::into_error)?.sync_all()?;
+        rename(temporary, path)
+    }
+
+    /// Writes the checkpoint in the binary file format.
+    #[inline]
+    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let (height, width) = self.counts.dim();
+        let dimension = |size: usize| u64::try_from(size).map_err(|err| Error::new(ErrorKind::InvalidData, err));
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&u32::try_from(C::BYTES).unwrap().to_le_bytes())?;
+        writer.write_all(&self.settings_hash.to_le_bytes())?;
+        writer.write_all(&dimension(height)?.to_le_bytes())?;
+        writer.write_all(&dimension(width)?.to_le_bytes())?;
+        writer.write_all(&self.num_samples.to_le_bytes())?;
+        for count in &self.counts {
+            writer.write_all(&count.to_u64().unwrap().to_le_bytes()[..C::BYTES])?;
+        }
+        writer.flush()
+    }
+
+    /// Reads a checkpoint from a file, widening its counts if they are narrower than `C`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not a valid checkpoint, or has counts wider than `C`.
+    /// The size of the image in the header is checked against the length of the file before any counts are allocated.
+    #[inline]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path)?;
+        let file_bytes = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(Error::new(ErrorKind::InvalidData, "Not a checkpoint file"));
+        }
+        let mut version = [0; 4];
+        reader.read_exact(&mut version)?;
+        if u32::from_le_bytes(version) != VERSION {
+            return Err(Error::new(ErrorKind::InvalidData, "Unsupported checkpoint version"));
+        }
+        let mut width_bytes = [0; 4];
+        reader.read_exact(&mut width_bytes)?;
+        let count_bytes = usize::try_from(u32::from_le_bytes(width_bytes)).unwrap_or(usize::MAX);
+        if !matches!(count_bytes, 4 | 8) || count_bytes > C::BYTES {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Cannot read {count_bytes}-byte checkpoint counts as {}-byte counts", C::BYTES),
+            ));
+        }
+
+        let settings_hash = read_u64(&mut reader)?;
+        let height = usize::try_from(read_u64(&mut reader)?).map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+        let width = usize::try_from(read_u64(&mut reader)?).map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+        let num_samples = read_u64(&mut reader)?;
+
+        // The header is untrusted, so the counts it describes must be exactly those remaining in the file
+        let count_data_bytes = height
+            .checked_mul(width)
+            .and_then(|pixels| pixels.checked_mul(count_bytes))
+            .and_then(|bytes| u64::try_from(bytes).ok());
+        if count_data_bytes != file_bytes.checked_sub(HEADER_BYTES) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Checkpoint image size does not match the file length",
+            ));
+        }
+
+        let mut counts = Array2::zeros([height, width]);
+        let mut count = [0; 8];
+        for value in &mut counts {
+            reader.read_exact(&mut count[..count_bytes])?;
+            *value = C::from_u64(u64::from_le_bytes(count))
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Checkpoint count out of range"))?;
+        }
+
+        Ok(Self {
+            settings_hash,
+            num_samples,
+            counts,
+        })
+    }
+}
+
+/// Reads a little-endian `u64`.
+#[inline]
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Hashes the settings which determine the meaning of a density.
+#[inline]
+fn settings_hash<T: Float, S>(settings: &Settings<T, S>) -> u64 {
+    let float = |value: T| value.to_f64().unwrap_or(f64::NAN).to_bits();
+    let words = [
+        u64::try_from(settings.resolution[0]).unwrap(),
+        u64::try_from(settings.resolution[1]).unwrap(),
+        float(settings.offset[0]),
+        float(settings.offset[1]),
+        float(settings.scale),
+        u64::try_from(settings.max_iter).unwrap(),
+        u64::try_from(settings.warmup).unwrap(),
+        settings.step_size.map_or(u64::MAX, float),
+    ];
+    let escape = settings
+        .escape
+        .map(|escape| [<u64 as From<bool>>::from(escape.plots(true)), float(escape.radius())]);
+    words
+        .iter()
+        .chain(escape.iter().flatten())
+        .flat_map(|word| word.to_le_bytes())
+        .fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ <u64 as From<u8>>::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
+/// Sums the densities stored in several checkpoint files, counting visits with the count type `C`.
+///
+/// Merging into `u64` counts avoids saturating the bright pixels of `u32` checkpoints as they are summed.
+///
+/// # Errors
+///
+/// Returns an error if no paths are given, if any file cannot be read or has counts wider than `C`,
+/// or if the checkpoints were produced with different settings.
+#[inline]
+pub fn merge<C: Count, P: AsRef<Path>>(paths: &[P]) -> Result<Checkpoint<C>> {
+    let (first, rest) = paths
+        .split_first()
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "No checkpoint files to merge"))?;
+    let mut total = Checkpoint::load(first)?;
+    for path in rest {
+        total.merge(&Checkpoint::load(path)?)?;
+    }
+    Ok(total)
+}
+
+/// Multi-threaded rendering of the attractor, resuming from a checkpoint.
+///
+/// Samples are accumulated onto `checkpoint` in batches of at most `batch_size` until it holds
+/// `settings.num_samples` samples. If a path is given the checkpoint is written to it after every batch.
+/// With a seed, the random streams continue from where the checkpoint left off, so a resumed render
+/// matches one which was never interrupted.
+///
+/// If `settings.cancellation` is cancelled, the batch in progress is discarded and the checkpoint of the
+/// completed batches is returned, holding fewer than `settings.num_samples` samples.
+/// Counts saturate at the maximum value of `C`, which [`Checkpoint::saturated_pixels`] reports.
+///
+/// # Errors
+///
+/// Returns an error if the settings are invalid, the batch size is zero, the checkpoint was produced with different
+/// settings, or it cannot be written.
+///
+/// # Panics
+///
+/// This function will not panic.
+#[inline]
+pub fn render_resumable<C, T, S>(
+    settings: &Settings<T, S>,
+    mut checkpoint: Checkpoint<C>,
+    path: Option<&Path>,
+    batch_size: usize,
+) -> Result<Checkpoint<C>>
+where
+    C: Count,
+    T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
+    StandardUniform: Distribution<T>,
+{
+    settings.validate().map_err(|err| Error::new(ErrorKind::InvalidInput, err))?;
+    if batch_size == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "Batch size must be positive"));
+    }
+    if checkpoint.settings_hash != settings_hash(settings) || checkpoint.counts.dim() != settings.resolution.into() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Checkpoint was produced with different settings",
+        ));
+    }
+
+    let target = u64::try_from(settings.num_samples).unwrap();
+    let max_batch = u64::try_from(batch_size).unwrap();
+
+    settings.progress.start(target, checkpoint.num_samples.min(target), "samples");
+
+    while checkpoint.num_samples < target {
+        let seed = settings.seed.unwrap_or_else(|| rng().random::<u64>());
+        // The last batch is clamped, so the checkpoint never holds more than the requested samples
+        let batch = (target - checkpoint.num_samples).min(max_batch);
+        let counts = render_density(settings, seed, checkpoint.num_samples, usize::try_from(batch).unwrap());
+
+        // A batch interrupted by cancellation is incomplete, so it is discarded
+        if settings.cancellation.is_cancelled() {
+            break;
+        }
+        saturating_sum(&mut checkpoint.counts, &counts);
+        checkpoint.num_samples += batch;
+
+        if let Some(checkpoint_path) = path {
+            checkpoint.save(checkpoint_path)?;
+        }
+    }
+    settings.progress.finish();
+
+    Ok(checkpoint)
+}
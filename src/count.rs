@@ -0,0 +1,125 @@
+//! Pixel count types for density images.
+//!
+//! Counts saturate at their maximum value instead of overflowing, so a bright pixel of a long render
+//! is clamped rather than wrapped. Renders report their saturated pixels, which can also be counted with
+//! [`saturated_pixels`], and which are removed by rendering with a wider count type.
+
+use ndarray::Array2;
+use num_traits::{Bounded, FromPrimitive, SaturatingAdd, ToPrimitive, Unsigned};
+use std::{
+    fmt::Debug,
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+};
+
+/// Unsigned integer type counting the visits of a pixel.
+pub trait Count:
+    Copy + Debug + PartialEq + Send + Sync + Unsigned + Bounded + SaturatingAdd + FromPrimitive + ToPrimitive + 'static
+{
+    /// Atomic counterpart, used when every thread accumulates into a single shared image.
+    type Atomic: Send + Sync;
+
+    /// Number of bytes in the binary representation of a count.
+    const BYTES: usize;
+
+    /// Creates an atomic counter starting at zero.
+    fn atomic_zero() -> Self::Atomic;
+
+    /// Adds one visit to an atomic counter, saturating at the maximum value.
+    fn atomic_increment(counter: &Self::Atomic);
+
+    /// Reads the value of an atomic counter.
+    fn atomic_load(counter: &Self::Atomic) -> Self;
+
+    /// Adds one visit, saturating at the maximum value.
+    #[must_use]
+    #[inline]
+    fn increment(self) -> Self {
+        self.saturating_add(&Self::one())
+    }
+
+    /// Whether the count has reached its maximum value, so further visits are lost.
+    #[inline]
+    fn is_saturated(self) -> bool {
+        self == Self::max_value()
+    }
+}
+
+impl Count for u32 {
+    type Atomic = AtomicU32;
+
+    const BYTES: usize = 4;
+
+    #[inline]
+    fn atomic_zero() -> Self::Atomic {
+        AtomicU32::new(0)
+    }
+
+    #[inline]
+    fn atomic_increment(counter: &Self::Atomic) {
+        let _previous = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| count.checked_add(1));
+    }
+
+    #[inline]
+    fn atomic_load(counter: &Self::Atomic) -> Self {
+        counter.load(Ordering::Relaxed)
+    }
+}
+
+impl Count for u64 {
+    type Atomic = AtomicU64;
+
+    const BYTES: usize = 8;
+
+    #[inline]
+    fn atomic_zero() -> Self::Atomic {
+        AtomicU64::new(0)
+    }
+
+    #[inline]
+    fn atomic_increment(counter: &Self::Atomic) {
+        let _previous = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| count.checked_add(1));
+    }
+
+    #[inline]
+    fn atomic_load(counter: &Self::Atomic) -> Self {
+        counter.load(Ordering::Relaxed)
+    }
+}
+
+/// Density image of a render, together with the number of its pixels whose counts saturated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Density<C> {
+    /// Visit count of each pixel.
+    pub counts: Array2<C>,
+    /// Number of pixels whose counts saturated, so some of their visits were lost.
+    ///
+    /// A non-zero value means the render should be repeated with a wider count type.
+    pub saturated: usize,
+}
+
+impl<C: Count> Density<C> {
+    /// Creates a `Density` from the visit count of each pixel, counting its saturated pixels.
+    #[must_use]
+    #[inline]
+    pub fn new(counts: Array2<C>) -> Self {
+        let saturated = saturated_pixels(&counts);
+        Self { counts, saturated }
+    }
+}
+
+/// Adds the counts of another image, saturating each pixel at the maximum value.
+#[inline]
+pub(crate) fn saturating_sum<C: Count>(counts: &mut Array2<C>, other: &Array2<C>) {
+    counts.zip_mut_with(other, |count, &other_count| *count = count.saturating_add(&other_count));
+}
+
+/// Number of pixels of a density image whose counts have saturated.
+///
+/// A non-zero result means some visits were lost, and the render should be repeated with a wider count type,
+/// such as `u64` with [`render_counts`](crate::render_counts).
+#[must_use]
+#[inline]
+pub fn saturated_pixels<C: Count>(counts: &Array2<C>) -> usize {
+    counts.iter().filter(|count| count.is_saturated()).count()
+}
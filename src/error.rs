@@ -0,0 +1,162 @@
+//! Error type reporting invalid input to the crate.
+//!
+//! Each variant describes one problem precisely, so that configurations read from files
+//! can be corrected without inspecting the rendering code.
+
+use num_traits::Float;
+use std::{
+    error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::ExpressionError;
+#[cfg(feature = "serde")]
+use crate::SpecError;
+
+/// Error produced by invalid settings, generators or specifications, or by a cancelled render.
+#[expect(
+    clippy::error_impl_error,
+    reason = "The crate error is exported as `attract::Error`, following the standard library convention."
+)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The image resolution `[height, width]` has a zero dimension.
+    ZeroResolution([usize; 2]),
+    /// The number of parallel groups is zero.
+    ZeroGroups,
+    /// The viewport scale is not positive and finite.
+    InvalidScale(f64),
+    /// The viewport offset `[real, imag]` is not finite.
+    InvalidOffset([f64; 2]),
+    /// The integration time step is not positive and finite.
+    InvalidStepSize(f64),
+    /// The escape radius is not positive and finite.
+    InvalidEscapeRadius(f64),
+    /// The tolerance within which points coincide is not positive and finite.
+    InvalidTolerance(f64),
+    /// The coverage grid of a parameter search has no cells.
+    ZeroGridResolution,
+    /// A range `[min, max]` is not finite, or its minimum exceeds its maximum.
+    InvalidRange([f64; 2]),
+    /// The number of parameter values differs from the number of parameters of an attractor.
+    ParameterCount {
+        /// Number of parameters.
+        expected: usize,
+        /// Number of values given.
+        found: usize,
+    },
+    /// A parameter of the generator is out of range.
+    InvalidGenerator {
+        /// Name of the parameter.
+        parameter: &'static str,
+        /// Value of the parameter.
+        value: f64,
+    },
+    /// The formulae of an expression could not be compiled.
+    Expression(ExpressionError),
+    /// The render was cancelled before it completed.
+    Cancelled,
+    /// A specification could not be built.
+    #[cfg(feature = "serde")]
+    Spec(SpecError),
+}
+
+impl Display for Error {
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Borrowing the nested errors requires matching through the reference."
+    )]
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroResolution([height, width]) => {
+                write!(f, "Resolution must be non-zero, but is {height} x {width}")
+            }
+            Self::ZeroGroups => write!(f, "Number of groups must be non-zero"),
+            Self::InvalidScale(scale) => write!(f, "Scale must be positive and finite, but is {scale}"),
+            Self::InvalidOffset([re, im]) => write!(f, "Offset must be finite, but is [{re}, {im}]"),
+            Self::InvalidStepSize(step_size) => write!(f, "Step size must be positive and finite, but is {step_size}"),
+            Self::InvalidEscapeRadius(radius) => write!(f, "Escape radius must be positive and finite, but is {radius}"),
+            Self::InvalidTolerance(tolerance) => write!(f, "Tolerance must be positive and finite, but is {tolerance}"),
+            Self::ZeroGridResolution => write!(f, "Grid resolution must be non-zero"),
+            Self::InvalidRange([min, max]) => {
+                write!(
+                    f,
+                    "Range must be finite with its minimum at most its maximum, but is [{min}, {max}]"
+                )
+            }
+            Self::ParameterCount { expected, found } => {
+                write!(f, "Expected {expected} parameter values, but found {found}")
+            }
+            Self::InvalidGenerator { parameter, value } => {
+                write!(f, "Generator {parameter} must be positive and finite, but is {value}")
+            }
+            Self::Cancelled => write!(f, "Rendering was cancelled"),
+            Self::Expression(error) => write!(f, "Invalid expression: {error}"),
+            #[cfg(feature = "serde")]
+            Self::Spec(error) => write!(f, "Invalid specification: {error}"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Borrowing the nested errors requires matching through the reference."
+    )]
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Expression(error) => Some(error),
+            #[cfg(feature = "serde")]
+            Self::Spec(error) => Some(error),
+            Self::ZeroResolution(_)
+            | Self::ZeroGroups
+            | Self::InvalidScale(_)
+            | Self::InvalidOffset(_)
+            | Self::InvalidStepSize(_)
+            | Self::InvalidEscapeRadius(_)
+            | Self::InvalidTolerance(_)
+            | Self::ZeroGridResolution
+            | Self::InvalidRange(_)
+            | Self::ParameterCount { .. }
+            | Self::InvalidGenerator { .. }
+            | Self::Cancelled => None,
+        }
+    }
+}
+
+impl From<ExpressionError> for Error {
+    #[inline]
+    fn from(error: ExpressionError) -> Self {
+        Self::Expression(error)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SpecError> for Error {
+    #[inline]
+    fn from(error: SpecError) -> Self {
+        Self::Spec(error)
+    }
+}
+
+/// Whether a value is positive and finite.
+pub(crate) fn is_positive<T: Float>(value: T) -> bool {
+    value.is_finite() && value > T::zero()
+}
+
+/// Checks that a range `[min, max]` is finite with its minimum at most its maximum.
+pub(crate) fn check_range<T: Float>([min, max]: [T; 2]) -> Result<(), Error> {
+    if min.is_finite() && max.is_finite() && min <= max {
+        Ok(())
+    } else {
+        Err(Error::InvalidRange([to_report(min), to_report(max)]))
+    }
+}
+
+/// Converts a value to `f64` for reporting in an `Error`.
+pub(crate) fn to_report<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
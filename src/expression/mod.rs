@@ -0,0 +1,269 @@
+//! Module defining attractors whose maps are parsed from formulae at runtime.
+//!
+//! Each formula gives the next value of one state variable in terms of the current variables,
+//! named parameters and the constants `pi`, `tau` and `e`. Formulae may use the operators
+//! `+`, `-`, `*`, `/` and `^`, the functions `sin`, `cos`, `tan`, `asin`, `acos`, `atan`, `sinh`,
+//! `cosh`, `tanh`, `exp`, `ln`, `sqrt`, `abs`, `floor`, `ceil` and `sign` of one argument,
+//! and `pow`, `atan2`, `min`, `max` and `mod` of two.
+//!
+//! Parameter values are substituted and constant subexpressions evaluated when the formulae are compiled,
+//! so the compiled closures only perform the work which depends on the state.
+
+use nalgebra::{Complex, SVector, Scalar};
+use num_traits::{Float, FloatConst};
+use rand::RngCore;
+use std::{
+    error,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+use crate::{Attractor, Error, Flow, Parametric};
+
+mod node;
+mod parser;
+
+use node::{Binary, Compiled, Unary};
+
+/// Error produced when formulae cannot be compiled into an `Expression`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExpressionError {
+    /// A formula is not well formed, or refers to an unknown name or function.
+    Syntax {
+        /// Index of the formula.
+        formula: usize,
+        /// Byte position of the error within the formula.
+        position: usize,
+        /// Description of the error.
+        message: String,
+    },
+    /// A variable or parameter name is repeated, or is the name of a constant or function.
+    NameConflict(String),
+    /// The number of formulae differs from the number of variables.
+    DimensionMismatch {
+        /// Number of variables.
+        variables: usize,
+        /// Number of formulae.
+        formulae: usize,
+    },
+}
+
+impl Display for ExpressionError {
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Borrowing the message and name requires matching through the reference."
+    )]
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax {
+                formula,
+                position,
+                message,
+            } => write!(f, "{message} in formula {formula} at position {position}"),
+            Self::NameConflict(name) => write!(f, "Name '{name}' is already in use"),
+            Self::DimensionMismatch { variables, formulae } => {
+                write!(f, "Expected {variables} formulae, one per variable, but found {formulae}")
+            }
+        }
+    }
+}
+
+impl error::Error for ExpressionError {}
+
+/// An attractor whose map is defined by one formula per state variable, parsed at runtime.
+///
+/// With two variables the expression iterates points of the complex plane, with the first variable
+/// as the real part and the second as the imaginary part. With `N` variables it iterates `N`-dimensional
+/// vectors, and may also be used as the derivative of a continuous-time `Flow`.
+#[derive(Clone)]
+pub struct Expression<T> {
+    /// Names of the state variables, in order.
+    variables: Vec<String>,
+    /// Source of the formula for each variable.
+    formulae: Vec<String>,
+    /// Names and values of the parameters.
+    parameters: Vec<(String, T)>,
+    /// Compiled formula for each variable.
+    maps: Vec<Compiled<T>>,
+}
+
+impl<T: Float + FloatConst + Send + Sync + 'static> Expression<T> {
+    /// Creates a new `Expression` from the names of the state variables, one formula per variable,
+    /// and the names and values of the parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a formula cannot be parsed, a name is used twice or shadows a constant or
+    /// function, or the number of formulae differs from the number of variables.
+    #[inline]
+    pub fn new(variables: &[&str], formulae: &[&str], parameters: &[(&str, T)]) -> Result<Self, ExpressionError> {
+        if variables.len() != formulae.len() {
+            return Err(ExpressionError::DimensionMismatch {
+                variables: variables.len(),
+                formulae: formulae.len(),
+            });
+        }
+
+        let mut names: Vec<&str> = Vec::with_capacity(variables.len() + parameters.len());
+        for name in variables.iter().copied().chain(parameters.iter().map(|&(name, _)| name)) {
+            let reserved =
+                parser::constant::<T>(name).is_some() || Unary::from_name(name).is_some() || Binary::from_name(name).is_some();
+            if reserved || names.contains(&name) {
+                return Err(ExpressionError::NameConflict(name.to_owned()));
+            }
+            names.push(name);
+        }
+
+        let owned_variables: Vec<String> = variables.iter().map(|&name| name.to_owned()).collect();
+        let owned_parameters: Vec<(String, T)> = parameters.iter().map(|&(name, value)| (name.to_owned(), value)).collect();
+        let maps = formulae
+            .iter()
+            .enumerate()
+            .map(|(index, formula)| parser::parse(index, formula, &owned_variables, &owned_parameters).map(node::Node::compile))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            variables: owned_variables,
+            formulae: formulae.iter().map(|&formula| formula.to_owned()).collect(),
+            parameters: owned_parameters,
+            maps,
+        })
+    }
+
+    /// Creates a new planar `Expression` from formulae for the variables `x` and `y`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a formula cannot be parsed, or a parameter is named `x` or `y`
+    /// or shadows a constant or function.
+    #[inline]
+    pub fn planar(x: &str, y: &str, parameters: &[(&str, T)]) -> Result<Self, ExpressionError> {
+        Self::new(&["x", "y"], &[x, y], parameters)
+    }
+}
+
+impl<T> Expression<T> {
+    /// Number of state variables.
+    #[must_use]
+    #[inline]
+    pub const fn dimension(&self) -> usize {
+        self.variables.len()
+    }
+
+    /// Names of the state variables, in order.
+    #[must_use]
+    #[inline]
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Source of the formula for each variable.
+    #[must_use]
+    #[inline]
+    pub fn formulae(&self) -> &[String] {
+        &self.formulae
+    }
+
+    /// Names and values of the parameters.
+    #[must_use]
+    #[inline]
+    pub fn parameters(&self) -> &[(String, T)] {
+        &self.parameters
+    }
+}
+
+impl<T: Debug> Debug for Expression<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Expression")
+            .field("variables", &self.variables)
+            .field("formulae", &self.formulae)
+            .field("parameters", &self.parameters)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Float> Attractor<T> for Expression<T> {
+    /// Iterates the expression on a point of the plane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression does not have exactly two variables.
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: Complex<T>, _rng: &mut dyn RngCore, _step: Option<T>) -> Complex<T> {
+        assert_eq!(self.maps.len(), 2, "Planar expressions must have two variables.");
+        let variables = [p.re, p.im];
+        Complex::new(self.maps[0](&variables), self.maps[1](&variables))
+    }
+}
+
+impl<T: Float + Scalar, const N: usize> Attractor<T, SVector<T, N>> for Expression<T> {
+    /// Iterates the expression on a vector state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of variables differs from the dimension of the state.
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: SVector<T, N>, _rng: &mut dyn RngCore, _step: Option<T>) -> SVector<T, N> {
+        assert_eq!(self.maps.len(), N, "Expression dimension must match the state dimension.");
+        SVector::from_fn(|row, _| self.maps[row](p.as_slice()))
+    }
+}
+
+impl<T: Float + Scalar, const N: usize> Flow<T, N> for Expression<T> {
+    /// Evaluates the formulae as the time derivatives of the variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of variables differs from the dimension of the state.
+    #[inline]
+    fn derivative(&self, _t: T, state: &SVector<T, N>) -> SVector<T, N> {
+        assert_eq!(self.maps.len(), N, "Expression dimension must match the state dimension.");
+        SVector::from_fn(|row, _| self.maps[row](state.as_slice()))
+    }
+}
+
+impl<T: Float + FloatConst + Send + Sync + 'static> Parametric<T> for Expression<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        self.parameters.iter().map(|entry| entry.0.as_str()).collect()
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        self.parameters.iter().map(|entry| entry.1).collect()
+    }
+
+    /// Ranges centred on the current values, extending either side by the magnitude of the value or one,
+    /// whichever is larger, since nothing is known about the dynamics of arbitrary formulae.
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        self.parameters
+            .iter()
+            .map(|entry| {
+                let half_width = entry.1.abs().max(T::one());
+                [entry.1 - half_width, entry.1 + half_width]
+            })
+            .collect()
+    }
+
+    /// Recompiles the formulae with the given parameter values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParameterCount`] if the number of values differs from the number of parameters.
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        if values.len() != self.parameters.len() {
+            return Err(Error::ParameterCount {
+                expected: self.parameters.len(),
+                found: values.len(),
+            });
+        }
+        let variables: Vec<&str> = self.variables.iter().map(String::as_str).collect();
+        let formulae: Vec<&str> = self.formulae.iter().map(String::as_str).collect();
+        let parameters: Vec<(&str, T)> = self.parameter_names().into_iter().zip(values.iter().copied()).collect();
+        Ok(Self::new(&variables, &formulae, &parameters)?)
+    }
+}
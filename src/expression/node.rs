@@ -0,0 +1,205 @@
+//! Syntax trees of formulae and their compilation into closures.
+
+use num_traits::Float;
+use std::sync::Arc;
+
+/// Compiled formula, evaluated on the variables of the state.
+pub(crate) type Compiled<T> = Arc<dyn Fn(&[T]) -> T + Send + Sync>;
+
+/// Operation of one argument.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Unary {
+    /// `-x`.
+    Negate,
+    /// `sin(x)`.
+    Sin,
+    /// `cos(x)`.
+    Cos,
+    /// `tan(x)`.
+    Tan,
+    /// `asin(x)`.
+    Asin,
+    /// `acos(x)`.
+    Acos,
+    /// `atan(x)`.
+    Atan,
+    /// `sinh(x)`.
+    Sinh,
+    /// `cosh(x)`.
+    Cosh,
+    /// `tanh(x)`.
+    Tanh,
+    /// `exp(x)`.
+    Exp,
+    /// `ln(x)`.
+    Ln,
+    /// `sqrt(x)`.
+    Sqrt,
+    /// `abs(x)`.
+    Abs,
+    /// `floor(x)`.
+    Floor,
+    /// `ceil(x)`.
+    Ceil,
+    /// `sign(x)`, which is one at positive zero.
+    Sign,
+}
+
+impl Unary {
+    /// Looks up a function of one argument by name.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "sin" => Self::Sin,
+            "cos" => Self::Cos,
+            "tan" => Self::Tan,
+            "asin" => Self::Asin,
+            "acos" => Self::Acos,
+            "atan" => Self::Atan,
+            "sinh" => Self::Sinh,
+            "cosh" => Self::Cosh,
+            "tanh" => Self::Tanh,
+            "exp" => Self::Exp,
+            "ln" => Self::Ln,
+            "sqrt" => Self::Sqrt,
+            "abs" => Self::Abs,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "sign" => Self::Sign,
+            _ => return None,
+        })
+    }
+
+    /// Applies the operation.
+    fn apply<T: Float>(self, x: T) -> T {
+        match self {
+            Self::Negate => -x,
+            Self::Sin => x.sin(),
+            Self::Cos => x.cos(),
+            Self::Tan => x.tan(),
+            Self::Asin => x.asin(),
+            Self::Acos => x.acos(),
+            Self::Atan => x.atan(),
+            Self::Sinh => x.sinh(),
+            Self::Cosh => x.cosh(),
+            Self::Tanh => x.tanh(),
+            Self::Exp => x.exp(),
+            Self::Ln => x.ln(),
+            Self::Sqrt => x.sqrt(),
+            Self::Abs => x.abs(),
+            Self::Floor => x.floor(),
+            Self::Ceil => x.ceil(),
+            Self::Sign => x.signum(),
+        }
+    }
+}
+
+/// Operation of two arguments.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Binary {
+    /// `x + y`.
+    Add,
+    /// `x - y`.
+    Subtract,
+    /// `x * y`.
+    Multiply,
+    /// `x / y`.
+    Divide,
+    /// `x ^ y`, or `pow(x, y)`.
+    Power,
+    /// `atan2(y, x)`, the angle of the point `(x, y)`.
+    Atan2,
+    /// `min(x, y)`.
+    Min,
+    /// `max(x, y)`.
+    Max,
+    /// `mod(x, y)`, the remainder with the sign of `y`.
+    Modulo,
+}
+
+impl Binary {
+    /// Looks up a function of two arguments by name.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "pow" => Self::Power,
+            "atan2" => Self::Atan2,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "mod" => Self::Modulo,
+            _ => return None,
+        })
+    }
+
+    /// Applies the operation.
+    fn apply<T: Float>(self, x: T, y: T) -> T {
+        match self {
+            Self::Add => x + y,
+            Self::Subtract => x - y,
+            Self::Multiply => x * y,
+            Self::Divide => x / y,
+            Self::Power => x.powf(y),
+            Self::Atan2 => x.atan2(y),
+            Self::Min => x.min(y),
+            Self::Max => x.max(y),
+            Self::Modulo => x - y * (x / y).floor(),
+        }
+    }
+}
+
+/// Syntax tree of a formula.
+#[derive(Debug, Clone)]
+pub(crate) enum Node<T> {
+    /// Literal, constant or parameter value.
+    Constant(T),
+    /// State variable, by index.
+    Variable(usize),
+    /// Operation applied to one subexpression.
+    Unary(Unary, Box<Self>),
+    /// Operation applied to two subexpressions.
+    Binary(Binary, Box<Self>, Box<Self>),
+}
+
+impl<T: Float> Node<T> {
+    /// Creates a unary node, evaluating it immediately if its argument is constant.
+    pub(crate) fn unary(operation: Unary, argument: Self) -> Self {
+        match argument {
+            Self::Constant(x) => Self::Constant(operation.apply(x)),
+            Self::Variable(_) | Self::Unary(..) | Self::Binary(..) => Self::Unary(operation, Box::new(argument)),
+        }
+    }
+
+    /// Creates a binary node, evaluating it immediately if both arguments are constant.
+    pub(crate) fn binary(operation: Binary, left: Self, right: Self) -> Self {
+        if let (&Self::Constant(x), &Self::Constant(y)) = (&left, &right) {
+            return Self::Constant(operation.apply(x, y));
+        }
+        Self::Binary(operation, Box::new(left), Box::new(right))
+    }
+}
+
+impl<T: Float + Send + Sync + 'static> Node<T> {
+    /// Compiles the tree into a closure over the state variables.
+    pub(crate) fn compile(self) -> Compiled<T> {
+        match self {
+            Self::Constant(value) => Arc::new(move |_| value),
+            Self::Variable(index) => Arc::new(move |variables| variables[index]),
+            Self::Unary(operation, argument) => {
+                let inner = argument.compile();
+                Arc::new(move |variables| operation.apply(inner(variables)))
+            }
+            Self::Binary(operation, left, right) => match (*left, *right) {
+                (Self::Constant(x), other) => {
+                    let inner = other.compile();
+                    Arc::new(move |variables| operation.apply(x, inner(variables)))
+                }
+                (other, Self::Constant(y)) => {
+                    let inner = other.compile();
+                    Arc::new(move |variables| operation.apply(inner(variables), y))
+                }
+                (first, second) => {
+                    let (lhs, rhs) = (first.compile(), second.compile());
+                    Arc::new(move |variables| operation.apply(lhs(variables), rhs(variables)))
+                }
+            },
+        }
+    }
+}
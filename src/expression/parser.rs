@@ -0,0 +1,279 @@
+//! Tokeniser and recursive-descent parser for formulae.
+//!
+//! The grammar, from lowest to highest precedence, is:
+//! - `sum     = product (('+' | '-') product)*`
+//! - `product = factor (('*' | '/') factor)*`
+//! - `factor  = ('-' | '+') factor | power`
+//! - `power   = primary ('^' factor)?`
+//! - `primary = number | name | name '(' sum (',' sum)* ')' | '(' sum ')'`
+//!
+//! so exponentiation is right-associative and binds more tightly than negation.
+//! Factors may be nested at most `MAX_DEPTH` deep, through signs, exponents, parentheses and function arguments,
+//! so that malicious formulae cannot overflow the stack.
+
+use num_traits::{Float, FloatConst};
+
+use crate::expression::{
+    ExpressionError,
+    node::{Binary, Node, Unary},
+};
+
+/// Maximum nesting depth of factors within a formula.
+const MAX_DEPTH: usize = 256;
+
+/// Lexical token of a formula.
+#[derive(Debug, Clone, Copy)]
+enum Token<'a> {
+    /// Numeric literal.
+    Number(f64),
+    /// Name of a variable, parameter, constant or function.
+    Name(&'a str),
+    /// Operator, parenthesis or comma.
+    Symbol(char),
+    /// End of the formula.
+    End,
+}
+
+/// Parser over the tokens of a single formula.
+struct Parser<'a, T> {
+    /// Index of the formula, for error reporting.
+    formula: usize,
+    /// Tokens of the formula and their byte positions.
+    tokens: Vec<(usize, Token<'a>)>,
+    /// Index of the next unconsumed token.
+    next: usize,
+    /// Number of factors currently being parsed.
+    depth: usize,
+    /// Names of the state variables, in order.
+    variables: &'a [String],
+    /// Names and values of the parameters.
+    parameters: &'a [(String, T)],
+}
+
+/// Parses a formula into a syntax tree, substituting parameter values and folding constant subexpressions.
+pub(crate) fn parse<T: Float + FloatConst>(
+    formula: usize,
+    source: &str,
+    variables: &[String],
+    parameters: &[(String, T)],
+) -> Result<Node<T>, ExpressionError> {
+    let mut parser = Parser {
+        formula,
+        tokens: tokenise(formula, source)?,
+        next: 0,
+        depth: 0,
+        variables,
+        parameters,
+    };
+    let node = parser.sum()?;
+    match parser.peek() {
+        (_, Token::End) => Ok(node),
+        (position, Token::Number(_) | Token::Name(_) | Token::Symbol(_)) => {
+            Err(parser.error(position, "Unexpected token after the end of the formula"))
+        }
+    }
+}
+
+/// Splits a formula into tokens, terminated by `Token::End`.
+fn tokenise(formula: usize, source: &str) -> Result<Vec<(usize, Token<'_>)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        if c.is_ascii_digit() || c == '.' {
+            let mut end = start + c.len_utf8();
+            let mut previous = c;
+            while let Some(&(position, next)) = chars.peek() {
+                let exponent_sign = (next == '+' || next == '-') && (previous == 'e' || previous == 'E');
+                if !(next.is_ascii_digit() || next == '.' || next == 'e' || next == 'E' || exponent_sign) {
+                    break;
+                }
+                end = position + next.len_utf8();
+                previous = next;
+                let _: Option<(usize, char)> = chars.next();
+            }
+            let literal = source.get(start..end).unwrap();
+            let value = literal.parse::<f64>().map_err(|_err| ExpressionError::Syntax {
+                formula,
+                position: start,
+                message: format!("Invalid number '{literal}'"),
+            })?;
+            tokens.push((start, Token::Number(value)));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start + c.len_utf8();
+            while let Some(&(position, next)) = chars.peek() {
+                if !(next.is_alphanumeric() || next == '_') {
+                    break;
+                }
+                end = position + next.len_utf8();
+                let _: Option<(usize, char)> = chars.next();
+            }
+            tokens.push((start, Token::Name(source.get(start..end).unwrap())));
+        } else if "+-*/^(),".contains(c) {
+            tokens.push((start, Token::Symbol(c)));
+        } else {
+            return Err(ExpressionError::Syntax {
+                formula,
+                position: start,
+                message: format!("Unexpected character '{c}'"),
+            });
+        }
+    }
+    tokens.push((source.len(), Token::End));
+    Ok(tokens)
+}
+
+impl<'a, T: Float + FloatConst> Parser<'a, T> {
+    /// Returns the next token without consuming it.
+    fn peek(&self) -> (usize, Token<'a>) {
+        self.tokens[self.next]
+    }
+
+    /// Consumes the next token if it is the given symbol.
+    fn accept(&mut self, symbol: char) -> bool {
+        let found = matches!(self.peek(), (_, Token::Symbol(c)) if c == symbol);
+        if found {
+            self.next += 1;
+        }
+        found
+    }
+
+    /// Consumes the next token, which must be the given symbol.
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), ExpressionError> {
+        if self.accept(symbol) {
+            Ok(())
+        } else {
+            Err(self.error(self.peek().0, &format!("Expected '{symbol}'")))
+        }
+    }
+
+    /// Creates a syntax error at the given position.
+    fn error(&self, position: usize, message: &str) -> ExpressionError {
+        ExpressionError::Syntax {
+            formula: self.formula,
+            position,
+            message: message.to_owned(),
+        }
+    }
+
+    /// Parses a sequence of terms joined by addition and subtraction.
+    fn sum(&mut self) -> Result<Node<T>, ExpressionError> {
+        let mut node = self.product()?;
+        loop {
+            if self.accept('+') {
+                node = Node::binary(Binary::Add, node, self.product()?);
+            } else if self.accept('-') {
+                node = Node::binary(Binary::Subtract, node, self.product()?);
+            } else {
+                return Ok(node);
+            }
+        }
+    }
+
+    /// Parses a sequence of factors joined by multiplication and division.
+    fn product(&mut self) -> Result<Node<T>, ExpressionError> {
+        let mut node = self.factor()?;
+        loop {
+            if self.accept('*') {
+                node = Node::binary(Binary::Multiply, node, self.factor()?);
+            } else if self.accept('/') {
+                node = Node::binary(Binary::Divide, node, self.factor()?);
+            } else {
+                return Ok(node);
+            }
+        }
+    }
+
+    /// Parses an optionally signed power, failing if factors are nested too deeply.
+    fn factor(&mut self) -> Result<Node<T>, ExpressionError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(self.peek().0, "Formula is nested too deeply"));
+        }
+        self.depth += 1;
+        let node = self.signed();
+        self.depth -= 1;
+        node
+    }
+
+    /// Parses an optionally signed power, within a factor.
+    fn signed(&mut self) -> Result<Node<T>, ExpressionError> {
+        if self.accept('-') {
+            Ok(Node::unary(Unary::Negate, self.factor()?))
+        } else if self.accept('+') {
+            self.factor()
+        } else {
+            self.power()
+        }
+    }
+
+    /// Parses a primary expression optionally raised to a power.
+    fn power(&mut self) -> Result<Node<T>, ExpressionError> {
+        let base = self.primary()?;
+        if self.accept('^') {
+            Ok(Node::binary(Binary::Power, base, self.factor()?))
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// Parses a number, name, function call or parenthesised expression.
+    fn primary(&mut self) -> Result<Node<T>, ExpressionError> {
+        let (position, token) = self.peek();
+        self.next += 1;
+        match token {
+            Token::Number(value) => Ok(Node::Constant(T::from(value).unwrap())),
+            Token::Name(name) if self.accept('(') => self.call(position, name),
+            Token::Name(name) => self.resolve(position, name),
+            Token::Symbol('(') => {
+                let node = self.sum()?;
+                self.expect_symbol(')')?;
+                Ok(node)
+            }
+            Token::Symbol(_) | Token::End => Err(self.error(position, "Expected a number, name or '('")),
+        }
+    }
+
+    /// Parses the arguments of a function call, after the opening parenthesis.
+    fn call(&mut self, position: usize, name: &str) -> Result<Node<T>, ExpressionError> {
+        let mut arguments = vec![self.sum()?];
+        while self.accept(',') {
+            arguments.push(self.sum()?);
+        }
+        self.expect_symbol(')')?;
+
+        let arity = arguments.len();
+        let mut values = arguments.into_iter();
+        match (Unary::from_name(name), Binary::from_name(name)) {
+            (Some(function), _) if arity == 1 => Ok(Node::unary(function, values.next().unwrap())),
+            (_, Some(function)) if arity == 2 => Ok(Node::binary(function, values.next().unwrap(), values.next().unwrap())),
+            (Some(_), _) => Err(self.error(position, &format!("Function '{name}' takes one argument"))),
+            (_, Some(_)) => Err(self.error(position, &format!("Function '{name}' takes two arguments"))),
+            (None, None) => Err(self.error(position, &format!("Unknown function '{name}'"))),
+        }
+    }
+
+    /// Resolves a name to a variable, parameter value or constant.
+    fn resolve(&self, position: usize, name: &str) -> Result<Node<T>, ExpressionError> {
+        if let Some(index) = self.variables.iter().position(|variable| variable == name) {
+            return Ok(Node::Variable(index));
+        }
+        if let Some(&(_, value)) = self.parameters.iter().find(|entry| entry.0 == name) {
+            return Ok(Node::Constant(value));
+        }
+        constant(name)
+            .map(Node::Constant)
+            .ok_or_else(|| self.error(position, &format!("Unknown name '{name}'")))
+    }
+}
+
+/// Returns the value of a named mathematical constant.
+pub(crate) fn constant<T: Float + FloatConst>(name: &str) -> Option<T> {
+    match name {
+        "pi" => Some(T::PI()),
+        "tau" => Some(T::TAU()),
+        "e" => Some(T::E()),
+        _ => None,
+    }
+}
@@ -0,0 +1,129 @@
+//! Module defining fractal flame attractors.
+//!
+//! A fractal flame is an iterated function system whose transforms are affine maps followed by
+//! blends of nonlinear variations. Each iteration applies one transform chosen at random in
+//! proportion to its weight, and moves the colour index of the point towards that of the transform.
+//! An optional final transform is applied to the plotted point without feeding back into the orbit.
+
+use nalgebra::Complex;
+use num_traits::{Float, FloatConst};
+use rand::{Rng as _, RngCore};
+
+use crate::{Attractor, State, random::WeightedChoice};
+
+mod transform;
+mod variation;
+
+pub use transform::FlameTransform;
+pub use variation::Variation;
+
+/// State of a point iterated through a fractal flame.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct FlameState<T> {
+    /// Position of the point in the orbit.
+    pub position: Complex<T>,
+    /// Colour index of the point, in [0, 1].
+    pub colour: T,
+    /// Plotted position, after the final transform.
+    pub output: Complex<T>,
+}
+
+impl<T: Float> State<T> for FlameState<T> {
+    /// Places the point at the sampled position, with a colour index of one half.
+    #[inline]
+    fn from_plane(p: Complex<T>) -> Self {
+        Self {
+            position: p,
+            colour: T::one() / (T::one() + T::one()),
+            output: p,
+        }
+    }
+}
+
+/// A fractal flame built from weighted transforms and an optional final transform.
+#[derive(Debug, Clone)]
+pub struct Flame<T> {
+    /// Transforms chosen between at each iteration.
+    transforms: Vec<FlameTransform<T>>,
+    /// Random choice of the transform applied at each iteration.
+    choice: WeightedChoice<T>,
+    /// Transform applied to the plotted point only.
+    final_transform: Option<FlameTransform<T>>,
+    /// Fraction of the distance moved towards the colour index of the applied transform.
+    colour_speed: T,
+}
+
+impl<T: Float + FloatConst> Flame<T> {
+    /// Creates a new `Flame` from the specified transforms and optional final transform.
+    ///
+    /// The weight and colour index of the final transform are unused.
+    /// Points move halfway towards the colour index of each applied transform.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no transforms or their weights sum to zero.
+    #[must_use]
+    #[inline]
+    pub fn new(transforms: Vec<FlameTransform<T>>, final_transform: Option<FlameTransform<T>>) -> Self {
+        let choice = WeightedChoice::new(transforms.iter().map(FlameTransform::weight));
+        Self {
+            transforms,
+            choice,
+            final_transform,
+            colour_speed: T::one() / (T::one() + T::one()),
+        }
+    }
+
+    /// Sets the fraction of the distance moved towards the colour index of each applied transform.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the speed is outside [0, 1].
+    #[must_use]
+    #[inline]
+    pub fn with_colour_speed(mut self, colour_speed: T) -> Self {
+        assert!(
+            colour_speed >= T::zero() && colour_speed <= T::one(),
+            "Colour speed must be in [0, 1]."
+        );
+        self.colour_speed = colour_speed;
+        self
+    }
+
+    /// Transforms chosen between at each iteration.
+    #[must_use]
+    #[inline]
+    pub fn transforms(&self) -> &[FlameTransform<T>] {
+        &self.transforms
+    }
+}
+
+impl<T: Float + FloatConst> Attractor<T, FlameState<T>> for Flame<T> {
+    /// Iterates the flame, drawing random choices from `rng`, as transforms have no time step to take.
+    #[inline]
+    fn iterate_at(&self, _n: usize, p: FlameState<T>, rng: &mut dyn RngCore, _step: Option<T>) -> FlameState<T> {
+        let transform = &self.transforms[self.choice.choose(rng)];
+        let mut position = transform.apply(p.position, rng);
+
+        // Restart orbits thrown to infinity, as some variations are singular
+        if !(position.re.is_finite() && position.im.is_finite()) {
+            position = Complex::new(
+                T::from(rng.random_range(-1.0..1.0)).unwrap(),
+                T::from(rng.random_range(-1.0..1.0)).unwrap(),
+            );
+        }
+
+        let colour = p.colour + self.colour_speed * (transform.colour() - p.colour);
+        let output = self
+            .final_transform
+            .as_ref()
+            .map_or(position, |final_transform| final_transform.apply(position, rng));
+
+        FlameState {
+            position,
+            colour,
+            output,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Weighted transforms making up a fractal flame.
+
+use nalgebra::Complex;
+use num_traits::{Float, FloatConst};
+use rand::RngCore;
+
+use crate::{Affine, Variation};
+
+/// A flame transform: an affine map followed by a weighted blend of variations.
+///
+/// The transformed point is `sum_v w_v V_v(affine(p))`.
+#[derive(Debug, Clone)]
+pub struct FlameTransform<T> {
+    /// Relative probability of the transform being chosen.
+    weight: T,
+    /// Affine map applied before the variations.
+    affine: Affine<T>,
+    /// Variations and their blend weights.
+    variations: Vec<(Variation, T)>,
+    /// Colour index in [0, 1] which points move towards when the transform is applied.
+    colour: T,
+}
+
+impl<T: Float + FloatConst> FlameTransform<T> {
+    /// Creates a new `FlameTransform` with the specified selection weight, affine map, variations and colour index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weight is negative or the colour index is outside [0, 1].
+    #[must_use]
+    #[inline]
+    pub fn new(weight: T, affine: Affine<T>, variations: Vec<(Variation, T)>, colour: T) -> Self {
+        assert!(weight >= T::zero(), "Transform weight must be non-negative.");
+        assert!(
+            colour >= T::zero() && colour <= T::one(),
+            "Transform colour index must be in [0, 1]."
+        );
+        Self {
+            weight,
+            affine,
+            variations,
+            colour,
+        }
+    }
+
+    /// Creates a new `FlameTransform` applying only the affine map.
+    #[must_use]
+    #[inline]
+    pub fn linear(weight: T, affine: Affine<T>, colour: T) -> Self {
+        Self::new(weight, affine, vec![(Variation::Linear, T::one())], colour)
+    }
+
+    /// Relative probability of the transform being chosen.
+    #[inline]
+    pub const fn weight(&self) -> T {
+        self.weight
+    }
+
+    /// Colour index which points move towards when the transform is applied.
+    #[inline]
+    pub const fn colour(&self) -> T {
+        self.colour
+    }
+
+    /// Applies the transform to a point, drawing any random choices from `rng`.
+    #[inline]
+    pub fn apply(&self, p: Complex<T>, rng: &mut dyn RngCore) -> Complex<T> {
+        let q = self.affine.apply(p);
+        self.variations
+            .iter()
+            .fold(Complex::new(T::zero(), T::zero()), |sum, &(variation, blend)| {
+                sum + variation.apply(q, rng).scale(blend)
+            })
+    }
+}
@@ -0,0 +1,105 @@
+//! Nonlinear variations applied by fractal flame transforms.
+
+use nalgebra::Complex;
+use num_traits::{Float, FloatConst};
+use rand::{Rng as _, RngCore};
+
+/// Nonlinear function of the plane applied after the affine part of a flame transform.
+///
+/// The equations follow the numbering and conventions of Draves and Reckase, *The Fractal Flame Algorithm*,
+/// with `r = sqrt(x^2 + y^2)` and `theta = atan2(x, y)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Variation {
+    /// `(x, y)`.
+    Linear,
+    /// `(sin(x), sin(y))`.
+    Sinusoidal,
+    /// `(x, y) / r^2`.
+    Spherical,
+    /// `(x sin(r^2) - y cos(r^2), x cos(r^2) + y sin(r^2))`.
+    Swirl,
+    /// `((x - y)(x + y), 2xy) / r`.
+    Horseshoe,
+    /// `(theta / pi, r - 1)`.
+    Polar,
+    /// `r (sin(theta + r), cos(theta - r))`.
+    Handkerchief,
+    /// `r (sin(theta r), -cos(theta r))`.
+    Heart,
+    /// `(theta / pi) (sin(pi r), cos(pi r))`.
+    Disc,
+    /// `(cos(theta) + sin(r), sin(theta) - cos(r)) / r`.
+    Spiral,
+    /// `(sin(theta) / r, r cos(theta))`.
+    Hyperbolic,
+    /// `(sin(theta) cos(r), cos(theta) sin(r))`.
+    Diamond,
+    /// `sqrt(r) (cos(theta / 2 + omega), sin(theta / 2 + omega))`, with `omega` randomly `0` or `pi`.
+    Julia,
+    /// `(x, y)` with negative `x` doubled and negative `y` halved.
+    Bent,
+    /// `2 (y, x) / (r + 1)`.
+    Fisheye,
+    /// `exp(x - 1) (cos(pi y), sin(pi y))`.
+    Exponential,
+    /// `(cos(pi x) cosh(y), -sin(pi x) sinh(y))`.
+    Cosine,
+    /// `2 (x, y) / (r + 1)`.
+    Eyefish,
+    /// `4 (x, y) / (r^2 + 4)`.
+    Bubble,
+    /// `(sin(x), y)`.
+    Cylinder,
+    /// `(sin(x) / cos(y), tan(y))`.
+    Tangent,
+}
+
+impl Variation {
+    /// Applies the variation to a point, drawing any random choices from `rng`.
+    #[inline]
+    pub fn apply<T: Float + FloatConst>(self, p: Complex<T>, rng: &mut dyn RngCore) -> Complex<T> {
+        let (x, y) = (p.re, p.im);
+        let r2 = (x * x + y * y).max(T::epsilon());
+        let r = r2.sqrt();
+        let theta = x.atan2(y);
+        let two = T::one() + T::one();
+
+        match self {
+            Self::Linear => p,
+            Self::Sinusoidal => Complex::new(x.sin(), y.sin()),
+            Self::Spherical => p.unscale(r2),
+            Self::Swirl => {
+                let (sin, cos) = r2.sin_cos();
+                Complex::new(x * sin - y * cos, x * cos + y * sin)
+            }
+            Self::Horseshoe => Complex::new((x - y) * (x + y), two * x * y).unscale(r),
+            Self::Polar => Complex::new(theta / T::PI(), r - T::one()),
+            Self::Handkerchief => Complex::new((theta + r).sin(), (theta - r).cos()).scale(r),
+            Self::Heart => Complex::new((theta * r).sin(), -(theta * r).cos()).scale(r),
+            Self::Disc => Complex::new((T::PI() * r).sin(), (T::PI() * r).cos()).scale(theta / T::PI()),
+            Self::Spiral => Complex::new(theta.cos() + r.sin(), theta.sin() - r.cos()).unscale(r),
+            Self::Hyperbolic => Complex::new(theta.sin() / r, r * theta.cos()),
+            Self::Diamond => Complex::new(theta.sin() * r.cos(), theta.cos() * r.sin()),
+            Self::Julia => {
+                let omega = if rng.random::<bool>() { T::PI() } else { T::zero() };
+                let (sin, cos) = (theta / two + omega).sin_cos();
+                Complex::new(cos, sin).scale(r.sqrt())
+            }
+            Self::Bent => Complex::new(
+                if x < T::zero() { two * x } else { x },
+                if y < T::zero() { y / two } else { y },
+            ),
+            Self::Fisheye => Complex::new(y, x).scale(two / (r + T::one())),
+            Self::Exponential => {
+                let (sin, cos) = (T::PI() * y).sin_cos();
+                Complex::new(cos, sin).scale((x - T::one()).exp())
+            }
+            Self::Cosine => Complex::new((T::PI() * x).cos() * y.cosh(), -(T::PI() * x).sin() * y.sinh()),
+            Self::Eyefish => p.scale(two / (r + T::one())),
+            Self::Bubble => p.scale((two + two) / (r2 + two + two)),
+            Self::Cylinder => Complex::new(x.sin(), y),
+            Self::Tangent => Complex::new(x.sin() / y.cos(), y.tan()),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Implementation of the Aizawa system.
+
+use nalgebra::{SVector, Scalar, Vector3};
+use num_traits::Float;
+
+use crate::{
+    Error, Flow, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Aizawa system defined by the equations:
+/// - `dx/dt = (z - b) * x - d * y`
+/// - `dy/dt = d * x + (z - b) * y`
+/// - `dz/dt = c + a * z - z^3 / 3 - (x^2 + y^2) * (1 + e * z) + f * z * x^3`
+#[derive(Debug, Clone, Copy)]
+pub struct Aizawa<T> {
+    /// Parameter 'a' in the Aizawa system.
+    a: T,
+    /// Parameter 'b' in the Aizawa system.
+    b: T,
+    /// Parameter 'c' in the Aizawa system.
+    c: T,
+    /// Parameter 'd' in the Aizawa system.
+    d: T,
+    /// Parameter 'e' in the Aizawa system.
+    e: T,
+    /// Parameter 'f' in the Aizawa system.
+    f: T,
+}
+
+impl<T> Aizawa<T> {
+    /// Creates a new `Aizawa` system with the specified parameters.
+    #[expect(
+        clippy::many_single_char_names,
+        reason = "Parameter names match the conventional notation of the equations."
+    )]
+    #[inline]
+    pub const fn new(a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+        Self { a, b, c, d, e, f }
+    }
+}
+
+impl<T: Float + Scalar> Flow<T, 3> for Aizawa<T> {
+    #[inline]
+    fn derivative(&self, _t: T, state: &SVector<T, 3>) -> SVector<T, 3> {
+        let (x, y, z) = (state.x, state.y, state.z);
+        let three = T::from(3.0).unwrap();
+        Vector3::new(
+            (z - self.b) * x - self.d * y,
+            self.d * x + (z - self.b) * y,
+            self.c + self.a * z - z * z * z / three - (x * x + y * y) * (T::one() + self.e * z) + self.f * z * x * x * x,
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Aizawa<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c", "d", "e", "f"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c, self.d, self.e, self.f]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.5, 1.5], [0.5, 1.0], [0.3, 0.9], [2.0, 5.0], [0.0, 0.5], [0.0, 0.2]])
+    }
+
+    #[expect(
+        clippy::many_single_char_names,
+        reason = "Parameter names match the conventional notation of the equations."
+    )]
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c, d, e, f] = parameter_array(values)?;
+        Ok(Self::new(a, b, c, d, e, f))
+    }
+}
@@ -0,0 +1,66 @@
+//! Implementation of the Chen system.
+
+use nalgebra::{SVector, Scalar, Vector3};
+use num_traits::Float;
+
+use crate::{
+    Error, Flow, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Chen system defined by the equations:
+/// - `dx/dt = a * (y - x)`
+/// - `dy/dt = (c - a) * x - x * z + c * y`
+/// - `dz/dt = x * y - b * z`
+#[derive(Debug, Clone, Copy)]
+pub struct Chen<T> {
+    /// Parameter 'a' in the Chen system.
+    a: T,
+    /// Parameter 'b' in the Chen system.
+    b: T,
+    /// Parameter 'c' in the Chen system.
+    c: T,
+}
+
+impl<T> Chen<T> {
+    /// Creates a new `Chen` system with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T, c: T) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Float + Scalar> Flow<T, 3> for Chen<T> {
+    #[inline]
+    fn derivative(&self, _t: T, state: &SVector<T, 3>) -> SVector<T, 3> {
+        let (x, y, z) = (state.x, state.y, state.z);
+        Vector3::new(
+            self.a * (y - x),
+            (self.c - self.a) * x - x * z + self.c * y,
+            x * y - self.b * z,
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Chen<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[30.0, 40.0], [2.0, 4.0], [20.0, 30.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c] = parameter_array(values)?;
+        Ok(Self::new(a, b, c))
+    }
+}
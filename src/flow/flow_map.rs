@@ -0,0 +1,88 @@
+//! Implementation of the time-step adapter turning a flow into an attractor.
+
+use nalgebra::{Complex, SVector, Scalar, Vector2};
+use num_traits::Float;
+use rand::RngCore;
+
+use crate::{Attractor, Error, Flow, Integrator, Parametric};
+
+/// Discrete map sampling a continuous flow at fixed time steps.
+///
+/// Each iteration advances the state by `step_size` using the wrapped integrator.
+/// Flows of any dimension evolve `nalgebra` vectors, and planar flows may also evolve points in the complex plane.
+/// Iteration `n` advances the flow from `t = n * dt`, so non-autonomous flows are sampled along their time axis
+/// when iterated through [`Attractor::iterate_at`], and frozen at `t = 0` by the entry points without an index.
+#[derive(Debug, Clone, Copy)]
+pub struct FlowMap<T, F, I> {
+    /// Flow defining the vector field.
+    flow: F,
+    /// Integrator used to advance the flow.
+    integrator: I,
+    /// Default time step taken per iteration.
+    step_size: T,
+}
+
+impl<T, F, I> FlowMap<T, F, I> {
+    /// Creates a new `FlowMap` advancing the flow by `step_size` per iteration.
+    #[inline]
+    pub const fn new(flow: F, integrator: I, step_size: T) -> Self {
+        Self {
+            flow,
+            integrator,
+            step_size,
+        }
+    }
+}
+
+impl<T: Float + Scalar, F: Flow<T, 2>, I: Integrator<T>> Attractor<T> for FlowMap<T, F, I> {
+    #[inline]
+    fn iterate_at(&self, n: usize, p: Complex<T>, _rng: &mut dyn RngCore, step: Option<T>) -> Complex<T> {
+        let dt = step.unwrap_or(self.step_size);
+        let state = self
+            .integrator
+            .step(&self.flow, start_time(n, dt), Vector2::new(p.re, p.im), dt);
+        Complex::new(state.x, state.y)
+    }
+}
+
+impl<T: Float + Scalar, F: Flow<T, N>, I: Integrator<T>, const N: usize> Attractor<T, SVector<T, N>> for FlowMap<T, F, I> {
+    #[inline]
+    fn iterate_at(&self, n: usize, p: SVector<T, N>, _rng: &mut dyn RngCore, step: Option<T>) -> SVector<T, N> {
+        let dt = step.unwrap_or(self.step_size);
+        self.integrator.step(&self.flow, start_time(n, dt), p, dt)
+    }
+}
+
+/// Time at which iteration `n` starts, for iterations of `dt`.
+#[inline]
+fn start_time<T: Float>(n: usize, dt: T) -> T {
+    T::from(n).unwrap() * dt
+}
+
+impl<T: Copy, F: Parametric<T>, I: Clone> Parametric<T> for FlowMap<T, F, I> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        self.flow.parameter_names()
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        self.flow.parameter_values()
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        self.flow.parameter_ranges()
+    }
+
+    /// Creates a copy of the flow map whose flow has the given parameter values,
+    /// keeping the integrator and step size.
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        Ok(Self::new(
+            self.flow.with_parameters(values)?,
+            self.integrator.clone(),
+            self.step_size,
+        ))
+    }
+}
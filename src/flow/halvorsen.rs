@@ -0,0 +1,63 @@
+//! Implementation of the Halvorsen system.
+
+use nalgebra::{SVector, Scalar, Vector3};
+use num_traits::Float;
+
+use crate::{
+    Error, Flow, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Halvorsen system defined by the equations:
+/// - `dx/dt = -a * x - 4 * y - 4 * z - y^2`
+/// - `dy/dt = -a * y - 4 * z - 4 * x - z^2`
+/// - `dz/dt = -a * z - 4 * x - 4 * y - x^2`
+#[derive(Debug, Clone, Copy)]
+pub struct Halvorsen<T> {
+    /// Parameter 'a' controlling the dissipation of the system.
+    a: T,
+}
+
+impl<T> Halvorsen<T> {
+    /// Creates a new `Halvorsen` system with the specified parameter.
+    #[inline]
+    pub const fn new(a: T) -> Self {
+        Self { a }
+    }
+}
+
+impl<T: Float + Scalar> Flow<T, 3> for Halvorsen<T> {
+    #[inline]
+    fn derivative(&self, _t: T, state: &SVector<T, 3>) -> SVector<T, 3> {
+        let (x, y, z) = (state.x, state.y, state.z);
+        let four = T::from(4.0).unwrap();
+        Vector3::new(
+            -self.a * x - four * y - four * z - y * y,
+            -self.a * y - four * z - four * x - z * z,
+            -self.a * z - four * x - four * y - x * x,
+        )
+    }
+}
+
+impl<T: Float> Parametric<T> for Halvorsen<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[1.0, 2.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a] = parameter_array(values)?;
+        Ok(Self::new(a))
+    }
+}
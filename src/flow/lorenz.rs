@@ -0,0 +1,62 @@
+//! Implementation of the Lorenz system.
+
+use nalgebra::{SVector, Scalar, Vector3};
+use num_traits::Float;
+
+use crate::{
+    Error, Flow, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The Lorenz system defined by the equations:
+/// - `dx/dt = sigma * (y - x)`
+/// - `dy/dt = x * (rho - z) - y`
+/// - `dz/dt = x * y - beta * z`
+#[derive(Debug, Clone, Copy)]
+pub struct Lorenz<T> {
+    /// Parameter 'sigma' (Prandtl number) in the Lorenz system.
+    sigma: T,
+    /// Parameter 'rho' (Rayleigh number) in the Lorenz system.
+    rho: T,
+    /// Parameter 'beta' (geometric factor) in the Lorenz system.
+    beta: T,
+}
+
+impl<T> Lorenz<T> {
+    /// Creates a new `Lorenz` system with the specified parameters.
+    #[inline]
+    pub const fn new(sigma: T, rho: T, beta: T) -> Self {
+        Self { sigma, rho, beta }
+    }
+}
+
+impl<T: Float + Scalar> Flow<T, 3> for Lorenz<T> {
+    #[inline]
+    fn derivative(&self, _t: T, state: &SVector<T, 3>) -> SVector<T, 3> {
+        let (x, y, z) = (state.x, state.y, state.z);
+        Vector3::new(self.sigma * (y - x), x * (self.rho - z) - y, x * y - self.beta * z)
+    }
+}
+
+impl<T: Float> Parametric<T> for Lorenz<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["sigma", "rho", "beta"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.sigma, self.rho, self.beta]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[5.0, 15.0], [20.0, 40.0], [1.0, 4.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [sigma, rho, beta] = parameter_array(values)?;
+        Ok(Self::new(sigma, rho, beta))
+    }
+}
@@ -0,0 +1,29 @@
+//! Module defining continuous-time flows and their discrete adapters.
+//!
+//! A flow is a vector field `f(t, state)` describing how a state evolves continuously in time.
+//! Flows are advanced by an [`Integrator`](crate::Integrator), and wrapped in a [`FlowMap`] to be
+//! sampled at fixed time steps so they can be rendered like any other attractor.
+
+use nalgebra::SVector;
+
+mod aizawa;
+mod chen;
+mod flow_map;
+mod halvorsen;
+mod lorenz;
+mod rossler;
+mod thomas;
+
+pub use aizawa::Aizawa;
+pub use chen::Chen;
+pub use flow_map::FlowMap;
+pub use halvorsen::Halvorsen;
+pub use lorenz::Lorenz;
+pub use rossler::Rossler;
+pub use thomas::Thomas;
+
+/// Trait defining the interface for continuous-time flow implementations.
+pub trait Flow<T, const N: usize> {
+    /// Evaluates the vector field `f(t, state)` at the given time and state.
+    fn derivative(&self, t: T, state: &SVector<T, N>) -> SVector<T, N>;
+}
@@ -0,0 +1,62 @@
+//! Implementation of the Rossler system.
+
+use nalgebra::{SVector, Scalar, Vector3};
+use num_traits::Float;
+
+use crate::{
+    Error, Flow, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// The `Rossler` system defined by the equations:
+/// - `dx/dt = -y - z`
+/// - `dy/dt = x + a * y`
+/// - `dz/dt = b + z * (x - c)`
+#[derive(Debug, Clone, Copy)]
+pub struct Rossler<T> {
+    /// Parameter 'a' in the `Rossler` system.
+    a: T,
+    /// Parameter 'b' in the `Rossler` system.
+    b: T,
+    /// Parameter 'c' in the `Rossler` system.
+    c: T,
+}
+
+impl<T> Rossler<T> {
+    /// Creates a new `Rossler` system with the specified parameters.
+    #[inline]
+    pub const fn new(a: T, b: T, c: T) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Float + Scalar> Flow<T, 3> for Rossler<T> {
+    #[inline]
+    fn derivative(&self, _t: T, state: &SVector<T, 3>) -> SVector<T, 3> {
+        let (x, y, z) = (state.x, state.y, state.z);
+        Vector3::new(-y - z, x + self.a * y, self.b + z * (x - self.c))
+    }
+}
+
+impl<T: Float> Parametric<T> for Rossler<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["a", "b", "c"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.a, self.b, self.c]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.1, 0.4], [0.1, 2.0], [4.0, 10.0]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [a, b, c] = parameter_array(values)?;
+        Ok(Self::new(a, b, c))
+    }
+}
@@ -0,0 +1,58 @@
+//! Implementation of Thomas' cyclically symmetric attractor.
+
+use nalgebra::{SVector, Scalar, Vector3};
+use num_traits::Float;
+
+use crate::{
+    Error, Flow, Parametric,
+    attractor::{parameter_array, recommended_ranges},
+};
+
+/// Thomas' cyclically symmetric system defined by the equations:
+/// - `dx/dt = sin(y) - b * x`
+/// - `dy/dt = sin(z) - b * y`
+/// - `dz/dt = sin(x) - b * z`
+#[derive(Debug, Clone, Copy)]
+pub struct Thomas<T> {
+    /// Parameter 'b' controlling the dissipation of the system.
+    b: T,
+}
+
+impl<T> Thomas<T> {
+    /// Creates a new `Thomas` system with the specified parameter.
+    #[inline]
+    pub const fn new(b: T) -> Self {
+        Self { b }
+    }
+}
+
+impl<T: Float + Scalar> Flow<T, 3> for Thomas<T> {
+    #[inline]
+    fn derivative(&self, _t: T, state: &SVector<T, 3>) -> SVector<T, 3> {
+        let (x, y, z) = (state.x, state.y, state.z);
+        Vector3::new(y.sin() - self.b * x, z.sin() - self.b * y, x.sin() - self.b * z)
+    }
+}
+
+impl<T: Float> Parametric<T> for Thomas<T> {
+    #[inline]
+    fn parameter_names(&self) -> Vec<&str> {
+        vec!["b"]
+    }
+
+    #[inline]
+    fn parameter_values(&self) -> Vec<T> {
+        vec![self.b]
+    }
+
+    #[inline]
+    fn parameter_ranges(&self) -> Vec<[T; 2]> {
+        recommended_ranges(&[[0.1, 0.25]])
+    }
+
+    #[inline]
+    fn with_parameters(&self, values: &[T]) -> Result<Self, Error> {
+        let [b] = parameter_array(values)?;
+        Ok(Self::new(b))
+    }
+}
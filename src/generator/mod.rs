@@ -10,7 +10,13 @@ use rand::{
     distr::{Distribution, StandardUniform, uniform::SampleUniform},
 };
 
+use crate::{
+    Error,
+    error::{is_positive, to_report},
+};
+
 /// Complex number generators.
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[non_exhaustive]
 pub enum Generator<T> {
     /// Samples points uniformly from an axis-aligned bounding box.
@@ -36,6 +42,31 @@ pub enum Generator<T> {
     },
 }
 
+impl<T: Float> Generator<T> {
+    /// Checks that the size of the sampled region is positive and finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidGenerator`] naming the first parameter which is out of range.
+    #[inline]
+    pub fn validate(&self) -> Result<(), Error> {
+        let parameters = match *self {
+            Self::Aabb { half_size, .. } => vec![("half width", half_size.re), ("half height", half_size.im)],
+            Self::Circle { radius, .. } => vec![("radius", radius)],
+            Self::Gaussian { std_dev, .. } => vec![("standard deviation", std_dev)],
+        };
+        parameters
+            .into_iter()
+            .find(|&(_, value)| !is_positive(value))
+            .map_or(Ok(()), |(parameter, value)| {
+                Err(Error::InvalidGenerator {
+                    parameter,
+                    value: to_report(value),
+                })
+            })
+    }
+}
+
 /// Trait defining the interface for generators.
 impl<T: Float + FloatConst + SampleUniform> Generator<T>
 where
@@ -45,7 +76,7 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if the standard deviation for a Gaussian generator is not positive,
+    /// Panics if the standard deviation for a Gaussian generator is not positive.
     #[must_use]
     #[inline]
     pub fn sample<R: Rng>(&self, rng: &mut R) -> Complex<T> {
@@ -0,0 +1,141 @@
+//! Implementation of the adaptive Dormand-Prince integrator.
+
+use nalgebra::{SVector, Scalar};
+use num_traits::Float;
+
+use crate::{Flow, Integrator, error::is_positive};
+
+/// Nodes of the Dormand-Prince tableau.
+const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+
+/// Stage coefficients of the Dormand-Prince tableau.
+#[rustfmt::skip]
+const A: [[f64; 6]; 7] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0, 0.0, 0.0],
+    [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0, 0.0],
+    [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
+];
+
+/// Fifth-order solution weights.
+#[rustfmt::skip]
+const B5: [f64; 7] = [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0, 0.0];
+
+/// Embedded fourth-order solution weights.
+const B4: [f64; 7] = [
+    5179.0 / 57600.0,
+    0.0,
+    7571.0 / 16695.0,
+    393.0 / 640.0,
+    -92097.0 / 339200.0,
+    187.0 / 2100.0,
+    1.0 / 40.0,
+];
+
+/// Adaptive fifth-order Dormand-Prince integrator with embedded fourth-order error estimate.
+///
+/// Each call to `step` advances the state by the full requested time step, subdividing it
+/// internally until the estimated local error of every sub-step is within `tolerance`.
+#[derive(Debug, Clone, Copy)]
+pub struct DormandPrince<T> {
+    /// Combined absolute and relative error tolerance per sub-step.
+    tolerance: T,
+}
+
+impl<T: Float> DormandPrince<T> {
+    /// Creates a new `DormandPrince` integrator with the specified error tolerance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` is not positive and finite, as sub-steps could then never be accepted.
+    #[inline]
+    pub fn new(tolerance: T) -> Self {
+        assert!(is_positive(tolerance), "Tolerance must be positive and finite");
+        Self { tolerance }
+    }
+}
+
+impl<T: Float + Scalar> DormandPrince<T> {
+    /// Attempts a single sub-step, returning the fifth-order solution and the normalised error estimate.
+    #[inline]
+    fn try_step<F: Flow<T, N> + ?Sized, const N: usize>(
+        &self,
+        flow: &F,
+        t: T,
+        state: &SVector<T, N>,
+        h: T,
+    ) -> (SVector<T, N>, T) {
+        let coeff = |value: f64| T::from(value).unwrap();
+
+        let mut k = [SVector::<T, N>::from_element(T::zero()); 7];
+        for stage in 0..7 {
+            let mut probe = *state;
+            for (j, kj) in k.iter().enumerate().take(stage) {
+                let a = coeff(A[stage][j]);
+                for i in 0..N {
+                    probe[i] = probe[i] + h * a * kj[i];
+                }
+            }
+            k[stage] = flow.derivative(t + coeff(C[stage]) * h, &probe);
+        }
+
+        let mut high = *state;
+        let mut error = T::zero();
+        for i in 0..N {
+            let mut delta_high = T::zero();
+            let mut delta_low = T::zero();
+            for (stage, ks) in k.iter().enumerate() {
+                delta_high = delta_high + coeff(B5[stage]) * ks[i];
+                delta_low = delta_low + coeff(B4[stage]) * ks[i];
+            }
+            high[i] = state[i] + h * delta_high;
+            let scale = self.tolerance * (T::one() + state[i].abs().max(high[i].abs()));
+            error = error.max((h * (delta_high - delta_low)).abs() / scale);
+        }
+
+        (high, error)
+    }
+}
+
+impl<T: Float + Scalar> Integrator<T> for DormandPrince<T> {
+    #[inline]
+    fn step<F: Flow<T, N> + ?Sized, const N: usize>(&self, flow: &F, t: T, state: SVector<T, N>, dt: T) -> SVector<T, N> {
+        let safety = T::from(0.9).unwrap();
+        let min_factor = T::from(0.2).unwrap();
+        let max_factor = T::from(5.0).unwrap();
+        let exponent = T::from(-0.2).unwrap();
+        let min_step = dt.abs() * T::epsilon().sqrt();
+
+        let mut elapsed = T::zero();
+        let mut current = state;
+        let mut h = dt;
+        loop {
+            // Never step past the end of the requested interval
+            let remaining = dt - elapsed;
+            let last = h.abs() >= remaining.abs();
+            if last {
+                h = remaining;
+            }
+
+            let (next, error) = self.try_step(flow, t + elapsed, &current, h);
+            if error.is_nan() || error <= T::one() || h.abs() <= min_step {
+                current = next;
+                if last {
+                    break;
+                }
+                elapsed = elapsed + h;
+            }
+
+            let factor = if error > T::zero() {
+                (safety * error.powf(exponent)).max(min_factor).min(max_factor)
+            } else {
+                max_factor
+            };
+            h = h * factor;
+        }
+        current
+    }
+}
@@ -0,0 +1,57 @@
+//! Implementation of the symplectic leapfrog integrator.
+
+use nalgebra::{SVector, Scalar};
+use num_traits::Float;
+
+use crate::{Flow, Integrator};
+
+/// Symplectic second-order leapfrog (kick-drift-kick) integrator.
+///
+/// The state is interpreted as `[q_1, .., q_k, p_1, .., p_k]`, with the first half holding
+/// positions and the second half holding momenta. The scheme is symplectic for separable
+/// Hamiltonian systems, where the position derivative depends only on momenta and the
+/// momentum derivative depends only on positions.
+///
+/// States must therefore have an even dimension, and stepping a state of odd dimension fails to compile.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Leapfrog;
+
+impl Leapfrog {
+    /// Creates a new `Leapfrog` integrator.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Float + Scalar> Integrator<T> for Leapfrog {
+    #[inline]
+    fn step<F: Flow<T, N> + ?Sized, const N: usize>(&self, flow: &F, t: T, state: SVector<T, N>, dt: T) -> SVector<T, N> {
+        const { assert!(N.is_multiple_of(2), "Leapfrog states must have an even dimension") };
+        let half = N / 2;
+        let half_dt = dt / T::from(2.0).unwrap();
+        let mut next = state;
+
+        // Half kick
+        let first_kick = flow.derivative(t, &next);
+        for i in half..N {
+            next[i] = next[i] + half_dt * first_kick[i];
+        }
+
+        // Full drift
+        let drift = flow.derivative(t + half_dt, &next);
+        for i in 0..half {
+            next[i] = next[i] + dt * drift[i];
+        }
+
+        // Half kick
+        let second_kick = flow.derivative(t + dt, &next);
+        for i in half..N {
+            next[i] = next[i] + half_dt * second_kick[i];
+        }
+
+        next
+    }
+}
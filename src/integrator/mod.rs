@@ -0,0 +1,22 @@
+//! Module defining numerical integrators for continuous-time flows.
+//!
+//! Integrators advance the state of a [`Flow`] by a single time step. Fixed-step,
+//! adaptive and symplectic schemes are provided.
+
+use nalgebra::SVector;
+
+use crate::Flow;
+
+mod dormand_prince;
+mod leapfrog;
+mod rk4;
+
+pub use dormand_prince::DormandPrince;
+pub use leapfrog::Leapfrog;
+pub use rk4::Rk4;
+
+/// Trait defining the interface for integrator implementations.
+pub trait Integrator<T> {
+    /// Advances `state` from time `t` to time `t + dt` under the given flow.
+    fn step<F: Flow<T, N> + ?Sized, const N: usize>(&self, flow: &F, t: T, state: SVector<T, N>, dt: T) -> SVector<T, N>;
+}
@@ -0,0 +1,40 @@
+//! Implementation of the classical fourth-order Runge-Kutta integrator.
+
+use nalgebra::{SVector, Scalar};
+use num_traits::Float;
+
+use crate::{Flow, Integrator};
+
+/// Classical fixed-step fourth-order Runge-Kutta integrator.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Rk4;
+
+impl Rk4 {
+    /// Creates a new `Rk4` integrator.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Float + Scalar> Integrator<T> for Rk4 {
+    #[inline]
+    fn step<F: Flow<T, N> + ?Sized, const N: usize>(&self, flow: &F, t: T, state: SVector<T, N>, dt: T) -> SVector<T, N> {
+        let two = T::from(2.0).unwrap();
+        let half_dt = dt / two;
+
+        let k1 = flow.derivative(t, &state);
+        let k2 = flow.derivative(t + half_dt, &state.zip_map(&k1, |s, k| s + k * half_dt));
+        let k3 = flow.derivative(t + half_dt, &state.zip_map(&k2, |s, k| s + k * half_dt));
+        let k4 = flow.derivative(t + dt, &state.zip_map(&k3, |s, k| s + k * dt));
+
+        let sixth_dt = dt / T::from(6.0).unwrap();
+        let mut next = state;
+        for i in 0..N {
+            next[i] = state[i] + sixth_dt * (k1[i] + two * k2[i] + two * k3[i] + k4[i]);
+        }
+        next
+    }
+}
@@ -101,6 +101,10 @@
     reason = "Rustfmt automatically shortens pub(in crate) to pub(crate)."
 )]
 #![allow(clippy::question_mark_used, reason = "The question mark operator is idiomatic in Rust.")]
+#![allow(
+    clippy::redundant_pub_crate,
+    reason = "Conflicts with the unreachable_pub lint for crate-internal items shared between modules."
+)]
 #![allow(
     clippy::separated_literal_suffix,
     reason = "Must chose between separated and unseparated literal suffixes."
@@ -119,12 +123,61 @@
 #![allow(clippy::unwrap_in_result, reason = "In some cases unwrap can be guaranteed to succeed.")]
 #![allow(clippy::unwrap_used, reason = "In some cases unwrap can be guaranteed to succeed.")]
 
+// Development dependencies are only used by the examples.
+#[cfg(test)]
+use {chromatic as _, nav as _, photo as _, serde as _, serde_yaml as _, vista as _};
+
+mod analysis;
 mod attractor;
+mod basin;
+mod bifurcation;
+mod channel;
+mod checkpoint;
+mod count;
+mod error;
+mod expression;
+mod flame;
+mod flow;
 mod generator;
+mod integrator;
+mod progress;
+mod projection;
+mod random;
 mod render;
 mod settings;
+#[cfg(feature = "serde")]
+mod spec;
+mod state;
+mod tonemap;
 
-pub use attractor::{Attractor, Chirikov, Clifford, DeJong, Duffing, Gingerbreadman, Henon, Ikeda, Tinkerbell};
+pub use analysis::{Candidate, LyapunovSpectrum, SearchSettings, lyapunov_spectrum, lyapunov_spectrum_numerical, search};
+pub use attractor::{
+    Affine, Alternate, ArnoldCat, Attractor, Bedhead, Chirikov, Clifford, Compose, DeJong, Duffing, Gingerbreadman,
+    GumowskiMira, Henon, Ifs, Ikeda, Jacobian, Julia, LotkaVolterra, Lozi, Mandelbrot, Modulated, Multibrot, Parametric,
+    Perturbed, Pickover, RandomSwitch, Schedule, Svensson, SymmetricIcon, SymmetricQuilt, Tinkerbell, Zaslavsky,
+};
+pub use basin::{Basin, BasinSettings, BasinSettingsBuilder, Outcome, render_basin};
+pub use bifurcation::{Family, Sweep, render_bifurcation};
+pub use channel::{Channel, Layers, render_channels};
+pub use checkpoint::{Checkpoint, merge, render_resumable};
+pub use count::{Count, Density, saturated_pixels};
+pub use error::Error;
+pub use expression::{Expression, ExpressionError};
+pub use flame::{Flame, FlameState, FlameTransform, Variation};
+pub use flow::{Aizawa, Chen, Flow, FlowMap, Halvorsen, Lorenz, Rossler, Thomas};
 pub use generator::Generator;
-pub use render::render;
-pub use settings::Settings;
+pub use integrator::{DormandPrince, Integrator, Leapfrog, Rk4};
+#[cfg(feature = "indicatif")]
+pub use progress::Bar;
+pub use progress::{Callback, Cancellation, Progress, Silent};
+pub use projection::{Camera, Lens, Planar, Projection};
+pub use render::{render, render_counts, try_render};
+pub use settings::{Accumulation, Escape, Settings, SettingsBuilder};
+#[cfg(feature = "serde")]
+pub use spec::{
+    Arguments, AttractorSpec, BuiltAttractor, CameraSpec, CheckpointConfig, Constructor, GeneratorSpec, IntegratorSpec,
+    Parameter, ProcessingConfig, Registry, RenderConfig, RenderingConfig, RotationSpec, SimulationConfig, SpecError,
+    WeightedSpec,
+};
+pub use state::{Coordinates, Orbit, State};
+pub use tonemap::{Normalisation, Operator, ToneMap};
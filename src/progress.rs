@@ -0,0 +1,197 @@
+//! Progress reporting and cooperative cancellation of renders.
+//!
+//! Rendering functions report the work they complete to a [`Progress`] observer, which may draw a
+//! terminal progress bar, forward the counts to a callback, or ignore them. A [`Cancellation`] token
+//! shared with another thread allows a long render to be stopped early.
+
+#[cfg(feature = "indicatif")]
+use indicatif::{ProgressBar, ProgressStyle};
+#[cfg(feature = "indicatif")]
+use std::sync::Mutex;
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+};
+
+/// Observer of the work completed by a render.
+///
+/// Units of work are counted from every thread of a render, so implementations must be thread-safe.
+pub trait Progress: Send + Sync {
+    /// Begins a render of `total` units of work, named by `unit`, of which `completed` are already done.
+    #[inline]
+    fn start(&self, _total: u64, _completed: u64, _unit: &str) {}
+
+    /// Records the completion of `amount` units of work.
+    fn advance(&self, amount: u64);
+
+    /// Ends the render, whether it completed or was cancelled.
+    #[inline]
+    fn finish(&self) {}
+}
+
+/// Progress observer which ignores all progress.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct Silent;
+
+impl Silent {
+    /// Creates a new `Silent` observer.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Progress for Silent {
+    #[inline]
+    fn advance(&self, _amount: u64) {}
+}
+
+/// Progress observer passing the number of completed and total units of work to a function.
+pub struct Callback<F> {
+    /// Function called with the completed and total units of work whenever they change.
+    function: F,
+    /// Units of work completed so far.
+    completed: AtomicU64,
+    /// Total units of work of the current render.
+    total: AtomicU64,
+}
+
+impl<F: Fn(u64, u64) + Send + Sync> Callback<F> {
+    /// Creates a new `Callback` observer calling `function(completed, total)` whenever progress is made.
+    #[must_use]
+    #[inline]
+    pub const fn new(function: F) -> Self {
+        Self {
+            function,
+            completed: AtomicU64::new(0),
+            total: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<F: Fn(u64, u64) + Send + Sync> Progress for Callback<F> {
+    #[inline]
+    fn start(&self, total: u64, completed: u64, _unit: &str) {
+        self.total.store(total, Ordering::Relaxed);
+        self.completed.store(completed, Ordering::Relaxed);
+        (self.function)(completed, total);
+    }
+
+    #[inline]
+    fn advance(&self, amount: u64) {
+        let completed = self.completed.fetch_add(amount, Ordering::Relaxed) + amount;
+        (self.function)(completed, self.total.load(Ordering::Relaxed));
+    }
+}
+
+impl<F> Debug for Callback<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Callback")
+            .field("completed", &self.completed)
+            .field("total", &self.total)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Progress observer drawing a progress bar on the terminal.
+#[cfg(feature = "indicatif")]
+#[derive(Debug)]
+pub struct Bar {
+    /// Progress bar of the current render, replaced at the start of each render.
+    bar: Mutex<ProgressBar>,
+}
+
+#[cfg(feature = "indicatif")]
+impl Bar {
+    /// Creates a new `Bar` observer, which is drawn once a render starts.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            bar: Mutex::new(ProgressBar::hidden()),
+        }
+    }
+}
+
+#[cfg(feature = "indicatif")]
+impl Default for Bar {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "indicatif")]
+impl Progress for Bar {
+    #[inline]
+    fn start(&self, total: u64, completed: u64, unit: &str) {
+        let bar = ProgressBar::new(total);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template(&format!(
+                    "[{{elapsed_precise}}] {{bar:50.cyan/blue}} {{pos}}/{{len}} {unit} ({{percent}}%) - {{eta_precise}} remaining"
+                ))
+                .unwrap()
+                .progress_chars("\u{2588}\u{2593}\u{2592}\u{2591}"),
+        );
+        bar.set_message("Rendering fractal...");
+        bar.set_position(completed.min(total));
+        *self.bar.lock().unwrap() = bar;
+    }
+
+    #[inline]
+    fn advance(&self, amount: u64) {
+        self.bar.lock().unwrap().inc(amount);
+    }
+
+    #[inline]
+    fn finish(&self) {
+        self.bar.lock().unwrap().finish_with_message("Rendering complete!");
+    }
+}
+
+/// Default progress observer, drawing a progress bar when the `indicatif` feature is enabled.
+#[inline]
+pub(crate) fn default_progress() -> Arc<dyn Progress> {
+    #[cfg(feature = "indicatif")]
+    return Arc::new(Bar::new());
+    #[cfg(not(feature = "indicatif"))]
+    return Arc::new(Silent::new());
+}
+
+/// Token for cooperatively cancelling a render from another thread.
+///
+/// Clones share the same state, so cancelling any clone cancels every render observing the token.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+    /// Whether cancellation has been requested.
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancellation {
+    /// Creates a new token which has not been cancelled.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests that renders observing this token stop as soon as possible.
+    #[inline]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether cancellation has been requested.
+    #[must_use]
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
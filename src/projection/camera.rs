@@ -0,0 +1,166 @@
+//! Implementation of a camera projecting N-dimensional states onto the image plane.
+
+use nalgebra::{Complex, SMatrix, SVector, Scalar, Vector3};
+use num_traits::Float;
+
+use crate::{Projection, error::is_positive};
+
+/// Lens model used by a `Camera`.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Lens<T> {
+    /// Parallel projection, discarding depth.
+    Orthographic,
+    /// Pinhole projection from an eye placed `distance` in front of the camera centre.
+    ///
+    /// Points in the plane through the centre keep their size, nearer points are magnified,
+    /// and points behind the eye are not visible.
+    Perspective {
+        /// Distance from the eye to the camera centre along the viewing axis.
+        distance: T,
+    },
+}
+
+/// Camera projecting N-dimensional states onto the image plane.
+///
+/// States are first translated so the camera centre sits at the origin and then rotated into
+/// camera space. The first two camera-space axes span the image plane (right and up) and the
+/// third axis points away from the viewer. Any further axes are discarded.
+#[derive(Debug, Clone, Copy)]
+pub struct Camera<T, const N: usize> {
+    /// Point in state space which appears at the centre of the image.
+    centre: SVector<T, N>,
+    /// Rotation from state space to camera space.
+    rotation: SMatrix<T, N, N>,
+    /// Lens model used to flatten camera space onto the image plane.
+    lens: Lens<T>,
+}
+
+impl<T: Float + Scalar, const N: usize> Camera<T, N> {
+    /// Creates a new `Camera` looking at `centre` along the third state axis.
+    ///
+    /// States must have at least two dimensions to span the image plane, and cameras of fewer fail to compile.
+    #[inline]
+    pub fn new(centre: SVector<T, N>, lens: Lens<T>) -> Self {
+        const { assert!(N >= 2, "Camera requires at least two dimensions") };
+        Self {
+            centre,
+            rotation: SMatrix::from_fn(|i, j| if i == j { T::one() } else { T::zero() }),
+            lens,
+        }
+    }
+
+    /// Creates a new orthographic `Camera` looking at `centre`.
+    #[inline]
+    pub fn orthographic(centre: SVector<T, N>) -> Self {
+        Self::new(centre, Lens::Orthographic)
+    }
+
+    /// Creates a new perspective `Camera` looking at `centre` from the given distance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is not positive and finite.
+    #[inline]
+    pub fn perspective(centre: SVector<T, N>, distance: T) -> Self {
+        assert!(is_positive(distance), "Distance must be positive and finite");
+        Self::new(centre, Lens::Perspective { distance })
+    }
+
+    /// Replaces the lens model of the camera.
+    #[must_use]
+    #[inline]
+    pub const fn with_lens(mut self, lens: Lens<T>) -> Self {
+        self.lens = lens;
+        self
+    }
+
+    /// Rotates the camera by `angle` radians within the plane spanned by camera axes `i` and `j`.
+    ///
+    /// Rotations compose, so repeated calls apply each rotation after the previous ones.
+    /// Any pair of axes may be used, which allows rotating four- and higher-dimensional states.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either axis is out of bounds.
+    #[must_use]
+    #[inline]
+    pub fn rotate(mut self, i: usize, j: usize, angle: T) -> Self {
+        assert!(i < N && j < N, "Rotation axes must be less than the state dimension");
+        let (sin, cos) = angle.sin_cos();
+        for col in 0..N {
+            let a = self.rotation[(i, col)];
+            let b = self.rotation[(j, col)];
+            self.rotation[(i, col)] = cos * a - sin * b;
+            self.rotation[(j, col)] = sin * a + cos * b;
+        }
+        self
+    }
+}
+
+impl<T: Float + Scalar> Camera<T, 3> {
+    /// Creates a new perspective `Camera` placed at `eye`, looking towards `target`, with `up` pointing up the image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `eye` and `target` coincide, or if `up` is parallel to the view direction.
+    #[inline]
+    pub fn look_at(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        let offset = target.zip_map(&eye, |a, b| a - b);
+        let distance = offset.x.hypot(offset.y).hypot(offset.z);
+        assert!(is_positive(distance), "Eye and target must be distinct");
+
+        let forward = offset.map(|v| v / distance);
+        let right = normalise(cross(&forward, &up));
+        let true_up = cross(&right, &forward);
+
+        Self {
+            centre: target,
+            rotation: SMatrix::from_fn(|i, j| match i {
+                0 => right[j],
+                1 => true_up[j],
+                _ => forward[j],
+            }),
+            lens: Lens::Perspective { distance },
+        }
+    }
+}
+
+/// Cross product of two three-vectors.
+#[inline]
+fn cross<T: Float + Scalar>(a: &Vector3<T>, b: &Vector3<T>) -> Vector3<T> {
+    Vector3::new(a.y * b.z - a.z * b.y, a.z * b.x - a.x * b.z, a.x * b.y - a.y * b.x)
+}
+
+/// Scales a three-vector to unit length.
+///
+/// # Panics
+///
+/// Panics if the vector is zero, which for the right axis of `look_at` means `up` is parallel to the view direction.
+#[inline]
+fn normalise<T: Float + Scalar>(v: Vector3<T>) -> Vector3<T> {
+    let length = v.x.hypot(v.y).hypot(v.z);
+    assert!(is_positive(length), "Up direction must not be parallel to the view direction");
+    v.map(|c| c / length)
+}
+
+impl<T: Float + Scalar, const N: usize> Projection<T, SVector<T, N>> for Camera<T, N> {
+    #[inline]
+    fn project(&self, p: &SVector<T, N>) -> Option<Complex<T>> {
+        let shifted = p.zip_map(&self.centre, |a, b| a - b);
+        let axis = |row: usize| (0..N).fold(T::zero(), |acc, col| acc + self.rotation[(row, col)] * shifted[col]);
+
+        let x = axis(0);
+        let y = axis(1);
+        match self.lens {
+            Lens::Orthographic => Some(Complex::new(x, y)),
+            Lens::Perspective { distance } => {
+                let depth = distance + if N > 2 { axis(2) } else { T::zero() };
+                (depth > T::zero()).then(|| {
+                    let factor = distance / depth;
+                    Complex::new(x * factor, y * factor)
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Module defining projections from attractor state space onto the image plane.
+//!
+//! Projections run before the viewport mapping, so every state is reduced to a point in the
+//! complex plane which is then binned into pixels.
+
+use nalgebra::Complex;
+
+mod camera;
+mod planar;
+
+pub use camera::{Camera, Lens};
+pub use planar::Planar;
+
+/// Trait defining the interface for projection implementations.
+pub trait Projection<T, S> {
+    /// Projects a state onto the image plane, returning `None` if the state is not visible.
+    fn project(&self, p: &S) -> Option<Complex<T>>;
+}
@@ -0,0 +1,51 @@
+//! Implementation of the planar projection.
+
+use nalgebra::{Complex, SVector, Scalar};
+
+use crate::{FlameState, Orbit, Projection};
+
+/// Projection keeping the first two components of the state and discarding the rest.
+///
+/// This is the identity for states in the complex plane.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Planar;
+
+impl Planar {
+    /// Creates a new `Planar` projection.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Copy> Projection<T, Complex<T>> for Planar {
+    #[inline]
+    fn project(&self, p: &Complex<T>) -> Option<Complex<T>> {
+        Some(*p)
+    }
+}
+
+impl<T: Scalar + Copy, const N: usize> Projection<T, SVector<T, N>> for Planar {
+    #[inline]
+    fn project(&self, p: &SVector<T, N>) -> Option<Complex<T>> {
+        (N >= 2).then(|| Complex::new(p[0], p[1]))
+    }
+}
+
+impl<T: Copy> Projection<T, FlameState<T>> for Planar {
+    /// Projects the plotted position, after the final transform of the flame.
+    #[inline]
+    fn project(&self, p: &FlameState<T>) -> Option<Complex<T>> {
+        Some(p.output)
+    }
+}
+
+impl<T: Copy> Projection<T, Orbit<T>> for Planar {
+    /// Projects the current point of the orbit.
+    #[inline]
+    fn project(&self, p: &Orbit<T>) -> Option<Complex<T>> {
+        Some(p.z)
+    }
+}
@@ -0,0 +1,106 @@
+//! Counter-based random number streams.
+//!
+//! Each output is a pure function of a key and a counter, so independent streams can be derived
+//! from a single seed and drawn from in any order without affecting each other. This keeps
+//! parallel rendering reproducible regardless of how work is scheduled across threads.
+
+use num_traits::{Float, FloatConst};
+use rand::{Rng as _, RngCore, rand_core::impls::fill_bytes_via_next};
+
+/// Weyl sequence increment used to space consecutive counters (the 64-bit golden ratio).
+const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Finalising bijection scrambling a 64-bit word (the `SplitMix64` output function).
+#[inline]
+const fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Random number stream whose `n`th output depends only on its key and `n`.
+#[derive(Debug, Clone)]
+pub(crate) struct CounterRng {
+    /// Key identifying the stream.
+    key: u64,
+    /// Index of the next output in the stream.
+    counter: u64,
+}
+
+impl CounterRng {
+    /// Creates the stream with the given index derived from `seed`.
+    #[inline]
+    pub(crate) const fn new(seed: u64, stream: u64) -> Self {
+        Self {
+            key: mix(seed ^ mix(stream.wrapping_add(GAMMA))),
+            counter: 0,
+        }
+    }
+}
+
+impl RngCore for CounterRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        u32::try_from(self.next_u64() >> 32).unwrap()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let value = mix(self.key.wrapping_add(self.counter.wrapping_mul(GAMMA)));
+        self.counter = self.counter.wrapping_add(1);
+        value
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        fill_bytes_via_next(self, dst);
+    }
+}
+
+/// Random choice between a fixed set of options, each chosen in proportion to its weight.
+#[derive(Debug, Clone)]
+pub(crate) struct WeightedChoice<T> {
+    /// Cumulative probabilities of the options.
+    cumulative: Vec<T>,
+}
+
+impl<T: Float> WeightedChoice<T> {
+    /// Creates the choice between options with the given non-negative weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weights sum to zero.
+    #[inline]
+    pub(crate) fn new<I: IntoIterator<Item = T>>(weights: I) -> Self {
+        let mut sum = T::zero();
+        let mut cumulative: Vec<T> = weights
+            .into_iter()
+            .map(|weight| {
+                sum = sum + weight;
+                sum
+            })
+            .collect();
+        assert!(sum > T::zero(), "At least one option must have a positive weight.");
+        for probability in &mut cumulative {
+            *probability = *probability / sum;
+        }
+        Self { cumulative }
+    }
+
+    /// Chooses the index of an option.
+    #[inline]
+    pub(crate) fn choose(&self, rng: &mut dyn RngCore) -> usize {
+        let u = T::from(rng.random::<f64>()).unwrap();
+        self.cumulative.partition_point(|&c| c <= u).min(self.cumulative.len() - 1)
+    }
+}
+
+/// Draws a pair of independent standard normal deviates by the Box-Muller transform.
+#[inline]
+pub(crate) fn normal_pair<T: Float + FloatConst>(rng: &mut dyn RngCore) -> (T, T) {
+    let u1 = T::one() - T::from(rng.random::<f64>()).unwrap();
+    let u2 = T::from(rng.random::<f64>()).unwrap();
+    let r = (T::from(-2.0).unwrap() * u1.ln()).sqrt();
+    let (sin, cos) = (T::TAU() * u2).sin_cos();
+    (r * cos, r * sin)
+}
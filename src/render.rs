@@ -3,18 +3,18 @@
 //! This module provides functionality to render attractors by iterating their equations
 //! and counting the density of points in a discretized grid.
 
-use indicatif::{ProgressBar, ProgressStyle};
 use nalgebra::Complex;
 use ndarray::Array2;
 use num_traits::{Float, FloatConst, FromPrimitive, NumCast};
 use rand::{
+    Rng, RngCore,
     distr::{Distribution, StandardUniform, uniform::SampleUniform},
     rng,
 };
-use rayon::prelude::*;
-use std::sync::Arc;
+use rayon::{current_num_threads, current_thread_index, prelude::*};
+use std::{iter::repeat_with, sync::Mutex};
 
-use crate::{Attractor, Settings};
+use crate::{Accumulation, Count, Density, Error, Escape, Settings, State, count::saturating_sum, random::CounterRng};
 
 /// Create a lambda function to map a position in the complex plane to a pixel in the image.
 #[inline]
@@ -45,106 +45,390 @@ fn create_position_to_pixel_mapper<T: Send + Sync + Float + NumCast>(
     }
 }
 
+/// Create a lambda function to map a pixel in the image to its position in the complex plane.
+///
+/// This is the inverse of the position to pixel mapping, so images produced with either mapping share an orientation.
+#[inline]
+pub(crate) fn create_pixel_to_position_mapper<T: Send + Sync + Float + NumCast>(
+    offset: Complex<T>,
+    scale: T,
+    resolution: [usize; 2],
+) -> impl Fn([usize; 2]) -> Complex<T> + Send + Sync {
+    let y_res = T::from(resolution[0]).unwrap();
+    let x_res = T::from(resolution[1]).unwrap();
+
+    let aspect_ratio = x_res / y_res;
+
+    let height = scale;
+    let width = scale * aspect_ratio;
+    let half_height = height / T::from(2.0).unwrap();
+    let half_width = width / T::from(2.0).unwrap();
+
+    let max_y = (y_res - T::one()).max(T::one());
+    let max_x = (x_res - T::one()).max(T::one());
+
+    move |[row, col]: [usize; 2]| {
+        let y = T::from(row).unwrap();
+        let x = T::from(col).unwrap();
+        Complex::new(
+            offset.re + half_width - (x / max_x) * width,
+            offset.im + half_height - (y / max_y) * height,
+        )
+    }
+}
+
+/// Per-pixel accumulation of orbit visits.
+pub(crate) trait Accumulator<T, S>: Send {
+    /// Records a visit to a pixel by an orbit in the given state, `iteration` steps after the warmup.
+    ///
+    /// The `position` is the projection of the state onto the image plane, and `previous` is the
+    /// projection of the preceding state if it was visible.
+    fn record(&mut self, pixel: [usize; 2], state: &S, position: Complex<T>, previous: Option<Complex<T>>, iteration: usize);
+
+    /// Adds the visits recorded by another accumulator.
+    fn combine(&mut self, other: Self);
+}
+
+/// Density image of atomic counters shared by every thread of a render.
+struct SharedCounts<'a, C: Count>(&'a Array2<C::Atomic>);
+
+impl<T, S, C: Count> Accumulator<T, S> for SharedCounts<'_, C> {
+    #[inline]
+    fn record(
+        &mut self,
+        pixel: [usize; 2],
+        _state: &S,
+        _position: Complex<T>,
+        _previous: Option<Complex<T>>,
+        _iteration: usize,
+    ) {
+        C::atomic_increment(&self.0[pixel]);
+    }
+
+    #[inline]
+    fn combine(&mut self, _other: Self) {}
+}
+
+impl<T, S, C: Count> Accumulator<T, S> for Array2<C> {
+    #[inline]
+    fn record(
+        &mut self,
+        pixel: [usize; 2],
+        _state: &S,
+        _position: Complex<T>,
+        _previous: Option<Complex<T>>,
+        _iteration: usize,
+    ) {
+        self[pixel] = self[pixel].increment();
+    }
+
+    #[inline]
+    fn combine(&mut self, other: Self) {
+        saturating_sum(self, &other);
+    }
+}
+
 /// Multi-threaded rendering of the attractor.
 ///
+/// Progress is reported to `settings.progress`. If `settings.cancellation` is cancelled, the density of the
+/// samples completed so far is returned. Counts saturate at `u32::MAX`; use [`render_counts`], which reports
+/// the number of saturated pixels, to check for saturation and to render with `u64` counts.
+///
+/// # Panics
+///
+/// Panics if the number of groups is zero. Use [`try_render`] to check the settings first.
+#[inline]
+pub fn render<T, S>(settings: &Settings<T, S>) -> Array2<u32>
+where
+    T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
+    StandardUniform: Distribution<T>,
+{
+    render_counts(settings).counts
+}
+
+/// Multi-threaded rendering of the attractor, counting visits with the count type `C`.
+///
+/// Counts saturate at the maximum value of `C` instead of overflowing, and the returned [`Density`] reports how
+/// many pixels saturated. Otherwise this is identical to [`render`].
+///
 /// # Panics
 ///
-/// This function will not panic.
+/// Panics if the number of groups is zero. Use [`Settings::validate`] to check the settings first.
+#[inline]
+pub fn render_counts<C, T, S>(settings: &Settings<T, S>) -> Density<C>
+where
+    C: Count,
+    T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
+    StandardUniform: Distribution<T>,
+{
+    settings
+        .progress
+        .start(u64::try_from(settings.num_samples).unwrap(), 0, "samples");
+
+    // Each group draws from its own random stream, so the result does not depend on scheduling.
+    let seed = settings.seed.unwrap_or_else(|| rng().random::<u64>());
+    let total_counts = render_density(settings, seed, 0, settings.num_samples);
+    settings.progress.finish();
+
+    Density::new(total_counts)
+}
+
+/// Multi-threaded rendering of the attractor, after checking the settings.
+///
+/// # Errors
+///
+/// Returns the problem reported by [`Settings::validate`] if the settings are invalid,
+/// or [`Error::Cancelled`] if the render was cancelled before it completed.
 #[inline]
-pub fn render<T>(settings: &Settings<T>) -> Array2<u32>
+pub fn try_render<T, S>(settings: &Settings<T, S>) -> Result<Array2<u32>, Error>
 where
     T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
     StandardUniform: Distribution<T>,
 {
-    let progress_bar = ProgressBar::new(u64::try_from(settings.num_samples).unwrap());
-    progress_bar.set_style(
-        ProgressStyle::default_bar()
-            .template("[{elapsed_precise}] {bar:50.cyan/blue} {pos}/{len} samples ({percent}%) - {eta_precise} remaining")
-            .unwrap()
-            .progress_chars("\u{2588}\u{2593}\u{2592}\u{2591}"),
-    );
-    progress_bar.set_message("Rendering fractal...");
-    let arc_progress_bar = Arc::new(progress_bar);
-
-    // Parallelize the group rendering (each group is single-threaded).
-    let group_counts: Vec<Array2<u32>> = (0..settings.num_groups)
-        .into_par_iter()
-        .map(|_group_index| render_group(settings, &Arc::clone(&arc_progress_bar)))
-        .collect();
-    arc_progress_bar.finish_with_message("Rendering complete!");
-
-    let mut total_counts = Array2::zeros(settings.resolution);
-    for counts in group_counts {
-        total_counts += &counts;
+    settings.validate()?;
+    let counts = render(settings);
+    if settings.cancellation.is_cancelled() {
+        return Err(Error::Cancelled);
     }
-    total_counts
+    Ok(counts)
+}
+
+/// Number of samples rendered by group `group_index` of a batch of `num_samples`.
+///
+/// Samples are split as evenly as possible, with the first `num_samples % num_groups` groups rendering one extra sample.
+#[inline]
+fn group_samples(num_samples: usize, num_groups: usize, group_index: usize) -> usize {
+    num_samples / num_groups + <usize as From<bool>>::from(group_index < num_samples % num_groups)
+}
+
+/// Multi-threaded rendering of the density of a batch of `num_samples` samples, split evenly across the groups.
+///
+/// Unlike [`render_batch`], the `Shared` accumulation strategy counts into a single image of atomic counters.
+#[inline]
+pub(crate) fn render_density<C, T, S>(settings: &Settings<T, S>, seed: u64, first_stream: u64, num_samples: usize) -> Array2<C>
+where
+    C: Count,
+    T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
+    StandardUniform: Distribution<T>,
+{
+    match settings.accumulation {
+        Accumulation::Shared => {
+            let counts = Array2::from_shape_simple_fn(settings.resolution, C::atomic_zero);
+            (0..settings.num_groups).into_par_iter().for_each(|group_index| {
+                let stream = first_stream + u64::try_from(group_index).unwrap();
+                let mut group_rng = CounterRng::new(seed, stream);
+                let samples = group_samples(num_samples, settings.num_groups, group_index);
+                render_group(settings, samples, &mut group_rng, &mut SharedCounts::<C>(&counts));
+            });
+            counts.map(C::atomic_load)
+        }
+        Accumulation::PerGroup | Accumulation::PerThread => render_batch(settings, seed, first_stream, num_samples, &|| {
+            Array2::zeros(settings.resolution)
+        }),
+    }
+}
+
+/// Multi-threaded rendering of a batch of `num_samples` samples, split evenly across the groups.
+///
+/// Group `g` draws from random stream `first_stream + g` of the given seed, and accumulates into an
+/// accumulator created by `empty`, either its own or that of its worker thread depending on the accumulation
+/// strategy. Groups stop early if the render is cancelled.
+///
+/// # Panics
+///
+/// Panics if a worker thread panicked whilst rendering.
+#[inline]
+pub(crate) fn render_batch<T, S, A, E>(
+    settings: &Settings<T, S>,
+    seed: u64,
+    first_stream: u64,
+    num_samples: usize,
+    empty: &E,
+) -> A
+where
+    T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
+    A: Accumulator<T, S>,
+    E: Fn() -> A + Sync,
+    StandardUniform: Distribution<T>,
+{
+    let accumulators: Vec<A> = match settings.accumulation {
+        // Parallelize the group rendering (each group is single-threaded).
+        Accumulation::PerGroup => (0..settings.num_groups)
+            .into_par_iter()
+            .map(|group_index| {
+                let stream = first_stream + u64::try_from(group_index).unwrap();
+                let mut group_rng = CounterRng::new(seed, stream);
+                let mut accumulator = empty();
+                let samples = group_samples(num_samples, settings.num_groups, group_index);
+                render_group(settings, samples, &mut group_rng, &mut accumulator);
+                accumulator
+            })
+            .collect(),
+        // The slots are counted on the calling thread, and the groups run on the same thread pool, so every worker
+        // index is below the number of slots and each worker only locks its own slot, which is never contended.
+        // Were a group to run elsewhere, the modulo and the lock would still keep the accumulation correct.
+        Accumulation::PerThread | Accumulation::Shared => {
+            let slots: Vec<Mutex<Option<A>>> = repeat_with(|| Mutex::new(None)).take(current_num_threads()).collect();
+            (0..settings.num_groups).into_par_iter().for_each(|group_index| {
+                let stream = first_stream + u64::try_from(group_index).unwrap();
+                let mut group_rng = CounterRng::new(seed, stream);
+                let samples = group_samples(num_samples, settings.num_groups, group_index);
+                let thread_index = current_thread_index().unwrap_or(0);
+                debug_assert!(
+                    thread_index < slots.len(),
+                    "Groups must run on the thread pool whose threads were counted"
+                );
+                let slot = &slots[thread_index % slots.len()];
+                let mut guard = slot.lock().unwrap();
+                render_group(settings, samples, &mut group_rng, guard.get_or_insert_with(empty));
+            });
+            slots.into_iter().filter_map(|slot| slot.into_inner().unwrap()).collect()
+        }
+    };
+
+    accumulators
+        .into_iter()
+        .reduce(|mut total, accumulator| {
+            total.combine(accumulator);
+            total
+        })
+        .unwrap_or_else(empty)
 }
 
 /// Single-threaded rendering of the attractor.
 #[inline]
-fn render_group<T>(settings: &Settings<T>, progress_bar: &Arc<ProgressBar>) -> Array2<u32>
+fn render_group<T, S, R, A>(settings: &Settings<T, S>, num_samples: usize, rng: &mut R, accumulator: &mut A)
 where
     T: Float + FloatConst + SampleUniform + NumCast + FromPrimitive + Send + Sync,
+    S: State<T>,
+    R: Rng,
+    A: Accumulator<T, S>,
     StandardUniform: Distribution<T>,
 {
     let offset = Complex::new(settings.offset[0], settings.offset[1]);
     let mapper = create_position_to_pixel_mapper(offset, settings.scale, settings.resolution);
 
-    // Create a counts array for this group
-    let mut counts = Array2::zeros(settings.resolution);
-    let mut rng = rng();
-
-    // Process samples sequentially
-    for i in 0..(settings.num_samples / settings.num_groups) {
-        let pos = settings.generator.sample(&mut rng);
-        render_path(
-            settings.attractor.as_ref(),
-            &mapper,
-            pos,
-            settings.max_iter,
-            settings.warmup,
-            &mut counts,
-        );
-
-        // Update progress bar every 100 samples to avoid too frequent updates
-        if i % 100 == 0 {
-            progress_bar.inc(100);
+    // Process samples sequentially, reporting progress every 100 samples to avoid too frequent updates
+    let mut unreported = 0;
+    for _ in 0..num_samples {
+        if settings.cancellation.is_cancelled() {
+            break;
         }
-    }
+        let pos = S::from_plane(settings.generator.sample(rng));
+        render_path(settings, &mapper, pos, rng, accumulator);
 
-    // Ensure we account for any remaining samples in the progress bar
-    let remainder = (settings.num_samples / settings.num_groups) % 100;
-    if remainder > 0 {
-        progress_bar.inc(u64::try_from(remainder).unwrap());
+        unreported += 1;
+        if unreported == 100 {
+            settings.progress.advance(unreported);
+            unreported = 0;
+        }
     }
 
-    counts
+    // Ensure we account for any remaining samples
+    if unreported > 0 {
+        settings.progress.advance(unreported);
+    }
 }
 
 /// Capture the path of a single sample point.
 #[inline]
-fn render_path<T>(
-    attractor: &(dyn Attractor<T> + Sync),
+fn render_path<T, S, A>(
+    settings: &Settings<T, S>,
     mapper: impl Fn(&Complex<T>) -> Option<[usize; 2]>,
-    start: Complex<T>,
-    max_iter: usize,
-    warmup: usize,
-    counts: &mut Array2<u32>,
+    start: S,
+    rng: &mut dyn RngCore,
+    accumulator: &mut A,
 ) where
     T: Float + NumCast + FromPrimitive,
+    S: State<T>,
+    A: Accumulator<T, S>,
 {
+    if let Some(escape) = settings.escape {
+        render_conditional_path(settings, mapper, start, escape, rng, accumulator);
+        return;
+    }
+
+    let attractor = settings.attractor.as_ref();
+    let mut next = |n, p| attractor.iterate_at(n, p, rng, settings.step_size);
     let mut pos = start;
 
     // Warmup phase - skip initial iterations to reach the attractor
-    for _ in 0..warmup {
-        pos = attractor.iterate(pos);
+    for n in 0..settings.warmup {
+        pos = next(n, pos);
     }
 
     // Count phase
-    for _ in 0..max_iter {
-        pos = attractor.iterate(pos);
-        if let Some([x, y]) = mapper(&pos) {
-            counts[[x, y]] += 1;
+    let mut previous = settings.projection.project(&pos);
+    for iteration in 0..settings.max_iter {
+        pos = next(settings.warmup + iteration, pos);
+        let position = settings.projection.project(&pos);
+        if let Some(q) = position
+            && let Some(pixel) = mapper(&q)
+        {
+            accumulator.record(pixel, &pos, q, previous, iteration);
+        }
+        previous = position;
+    }
+}
+
+/// Capture the path of a single sample point, plotting it only if it meets the escape condition.
+///
+/// The orbit is buffered until it escapes or reaches the maximum number of iterations, and then committed or discarded.
+#[inline]
+fn render_conditional_path<T, S, A>(
+    settings: &Settings<T, S>,
+    mapper: impl Fn(&Complex<T>) -> Option<[usize; 2]>,
+    start: S,
+    escape: Escape<T>,
+    rng: &mut dyn RngCore,
+    accumulator: &mut A,
+) where
+    T: Float + NumCast + FromPrimitive,
+    S: State<T>,
+    A: Accumulator<T, S>,
+{
+    let attractor = settings.attractor.as_ref();
+    let mut next = |n, p| attractor.iterate_at(n, p, rng, settings.step_size);
+    let radius_sqr = escape.radius() * escape.radius();
+    let is_outside = |position: Option<Complex<T>>| position.is_some_and(|q| q.norm_sqr() > radius_sqr);
+    let mut pos = start;
+
+    // Warmup phase - orbits escaping before any point is plotted have nothing to commit
+    for n in 0..settings.warmup {
+        pos = next(n, pos);
+        if is_outside(settings.projection.project(&pos)) {
+            return;
+        }
+    }
+
+    // Buffer phase
+    let mut orbit = Vec::new();
+    let mut escaped = false;
+    let mut previous = settings.projection.project(&pos);
+    for iteration in 0..settings.max_iter {
+        pos = next(settings.warmup + iteration, pos);
+        let position = settings.projection.project(&pos);
+        if is_outside(position) {
+            escaped = true;
+            break;
+        }
+        if let Some(q) = position
+            && let Some(pixel) = mapper(&q)
+        {
+            orbit.push((pixel, pos, q, previous, iteration));
+        }
+        previous = position;
+    }
+
+    // Commit phase
+    if escape.plots(escaped) {
+        for (pixel, state, position, previous_position, iteration) in orbit {
+            accumulator.record(pixel, &state, position, previous_position, iteration);
         }
     }
 }
@@ -1,23 +1,90 @@
 //! Configuration settings for attractor rendering.
 //!
 //! This module provides a structure to configure the parameters for rendering
-//! attractors, including resolution, scale, and sampling methods.
+//! attractors, including resolution, scale, and sampling methods, and a builder
+//! filling in sensible defaults.
 
-use crate::{Attractor, Generator};
+use nalgebra::Complex;
+use num_traits::Float;
+use rayon::current_num_threads;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::{
+    Attractor, Cancellation, Error, Generator, Planar, Progress, Projection,
+    error::{is_positive, to_report},
+    progress::default_progress,
+};
+
+/// Condition on whether an orbit escapes, deciding if it is plotted.
+///
+/// An orbit escapes when its projection onto the image plane leaves the disc of the given radius
+/// about the origin, after which it is no longer iterated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Escape<T> {
+    /// Plot only the orbits which escape within the maximum number of iterations, as in the Buddhabrot.
+    Escaping(T),
+    /// Plot only the orbits which never escape, as in the anti-Buddhabrot.
+    Bounded(T),
+}
+
+impl<T: Copy> Escape<T> {
+    /// Radius of the escape disc.
+    #[inline]
+    pub const fn radius(&self) -> T {
+        match *self {
+            Self::Escaping(radius) | Self::Bounded(radius) => radius,
+        }
+    }
+
+    /// Whether an orbit with the given outcome is plotted.
+    #[inline]
+    pub const fn plots(&self, escaped: bool) -> bool {
+        match *self {
+            Self::Escaping(_) => escaped,
+            Self::Bounded(_) => !escaped,
+        }
+    }
+}
+
+/// Strategy for accumulating the visits of the groups of a render.
+///
+/// Counts are identical under every strategy, but the order in which channel sums are combined differs,
+/// so only `PerGroup` makes channel means bit-identical between seeded renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Accumulation {
+    /// Each group accumulates into its own image, and the images are summed once every group has finished.
+    /// Memory scales with `num_groups`.
+    #[default]
+    PerGroup,
+    /// Each rayon worker thread accumulates into its own image, shared by the groups it renders.
+    /// Memory scales with the number of threads.
+    PerThread,
+    /// All threads accumulate the density into a single image of atomic counters, using the memory of two images
+    /// regardless of the number of threads. Channels, which cannot be accumulated atomically, fall back to `PerThread`.
+    Shared,
+}
 
 /// Configuration settings for rendering.
-#[expect(
-    clippy::exhaustive_structs,
-    reason = "Settings struct is expected to be constructed directly."
-)]
-pub struct Settings<'a, T> {
+///
+/// The state type `S` defaults to a point in the complex plane. Settings are constructed with a
+/// [`SettingsBuilder`], and own their components, so they can be cloned, modified and sent between threads.
+#[non_exhaustive]
+pub struct Settings<T, S = Complex<T>> {
     // Scientific parameters
-    /// Reference to the attractor being rendered.
-    pub attractor: Box<dyn Attractor<T> + Sync>,
-    /// Reference to the generator used for sampling initial points.
-    pub generator: &'a Generator<T>,
+    /// Attractor being rendered, shared between clones of the settings.
+    pub attractor: Arc<dyn Attractor<T, S> + Send + Sync>,
+    /// Generator used for sampling initial points.
+    pub generator: Generator<T>,
 
     // Rendering parameters
+    /// Projection from the attractor state space onto the complex plane, shared between clones of the settings.
+    pub projection: Arc<dyn Projection<T, S> + Send + Sync>,
     /// Resolution of the output image [height, width].
     pub resolution: [usize; 2],
     /// Offset of the rendering viewport in the complex plane [real, imag].
@@ -30,10 +97,313 @@ pub struct Settings<'a, T> {
     pub num_samples: usize,
     /// Number of parallel groups for multi-threaded rendering.
     pub num_groups: usize,
+    /// Seed for the random sampling of initial points, making renders reproducible when set.
+    pub seed: Option<u64>,
+    /// Strategy for accumulating the visits of the groups.
+    pub accumulation: Accumulation,
 
     // Simulation parameters
     /// Maximum number of iterations per sample point.
     pub max_iter: usize,
     /// Number of warmup iterations before plotting point positions.
     pub warmup: usize,
+    /// Integration time step for attractors sampled from continuous flows, overriding their own step size when set.
+    pub step_size: Option<T>,
+    /// Condition on orbits escaping, plotting only escaping or bounded orbits when set.
+    pub escape: Option<Escape<T>>,
+
+    // Monitoring parameters
+    /// Observer of the samples completed, shared between clones of the settings.
+    pub progress: Arc<dyn Progress>,
+    /// Token stopping the render early when cancelled, shared between clones of the settings.
+    pub cancellation: Cancellation,
+}
+
+impl<T: Clone, S> Clone for Settings<T, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            attractor: Arc::clone(&self.attractor),
+            generator: self.generator.clone(),
+            projection: Arc::clone(&self.projection),
+            resolution: self.resolution,
+            offset: self.offset.clone(),
+            scale: self.scale.clone(),
+            num_samples: self.num_samples,
+            num_groups: self.num_groups,
+            seed: self.seed,
+            accumulation: self.accumulation,
+            max_iter: self.max_iter,
+            warmup: self.warmup,
+            step_size: self.step_size.clone(),
+            escape: self.escape.clone(),
+            progress: Arc::clone(&self.progress),
+            cancellation: self.cancellation.clone(),
+        }
+    }
+}
+
+impl<T: Float + 'static, S: 'static> Settings<T, S>
+where
+    Planar: Projection<T, S>,
+{
+    /// Starts building settings for rendering the given attractor, with the defaults of [`SettingsBuilder::new`].
+    #[must_use]
+    #[inline]
+    pub fn builder<A: Attractor<T, S> + Send + Sync + 'static>(attractor: A) -> SettingsBuilder<T, S> {
+        SettingsBuilder::new(attractor)
+    }
+}
+
+impl<T: Float, S> Settings<T, S> {
+    /// Checks that the settings describe a render which can be carried out as requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first problem found:
+    ///
+    /// - [`Error::ZeroResolution`] if the image has no pixels.
+    /// - [`Error::ZeroGroups`] if there are no groups to render the samples.
+    /// - [`Error::InvalidScale`] or [`Error::InvalidOffset`] if the viewport is degenerate.
+    /// - [`Error::InvalidStepSize`] or [`Error::InvalidEscapeRadius`] if the simulation parameters are out of range.
+    /// - [`Error::InvalidGenerator`] if the generator samples from an empty region.
+    #[inline]
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.resolution.contains(&0) {
+            return Err(Error::ZeroResolution(self.resolution));
+        }
+        if self.num_groups == 0 {
+            return Err(Error::ZeroGroups);
+        }
+        if !is_positive(self.scale) {
+            return Err(Error::InvalidScale(to_report(self.scale)));
+        }
+        if !self.offset.iter().all(|component| component.is_finite()) {
+            return Err(Error::InvalidOffset(self.offset.map(to_report)));
+        }
+        if let Some(step_size) = self.step_size
+            && !is_positive(step_size)
+        {
+            return Err(Error::InvalidStepSize(to_report(step_size)));
+        }
+        if let Some(escape) = self.escape
+            && !is_positive(escape.radius())
+        {
+            return Err(Error::InvalidEscapeRadius(to_report(escape.radius())));
+        }
+        self.generator.validate()
+    }
+}
+
+/// Builder for [`Settings`], starting from defaults suitable for a quick preview.
+pub struct SettingsBuilder<T, S = Complex<T>> {
+    /// Settings built so far, with `num_groups` ignored when chosen automatically.
+    settings: Settings<T, S>,
+    /// Whether to choose the number of groups from the rayon thread pool when building.
+    auto_groups: bool,
+}
+
+impl<T: Float + 'static, S: 'static> SettingsBuilder<T, S>
+where
+    Planar: Projection<T, S>,
+{
+    /// Creates a builder owning the given attractor.
+    ///
+    /// The defaults are a planar projection of initial points sampled from the unit square about the origin,
+    /// rendered at a resolution of 1024 x 1024 over a viewport of height 4 centred on the origin, with
+    /// 1,000,000 samples of 1000 iterations each after a warmup of 100 iterations. The number of groups is
+    /// chosen from the rayon thread pool unless set explicitly. Progress is drawn on the terminal when the
+    /// `indicatif` feature is enabled, and otherwise ignored.
+    #[must_use]
+    #[inline]
+    pub fn new<A: Attractor<T, S> + Send + Sync + 'static>(attractor: A) -> Self {
+        Self::shared(Arc::new(attractor))
+    }
+
+    /// Creates a builder sharing an attractor with other settings, with the same defaults as [`SettingsBuilder::new`].
+    ///
+    /// # Panics
+    ///
+    /// This function will not panic.
+    #[must_use]
+    #[inline]
+    pub fn shared(attractor: Arc<dyn Attractor<T, S> + Send + Sync>) -> Self {
+        Self {
+            settings: Settings {
+                attractor,
+                generator: Generator::Aabb {
+                    centre: Complex::new(T::zero(), T::zero()),
+                    half_size: Complex::new(T::one(), T::one()),
+                },
+                projection: Arc::new(Planar::new()),
+                resolution: [1024, 1024],
+                offset: [T::zero(), T::zero()],
+                scale: T::from(4.0).unwrap(),
+                num_samples: 1_000_000,
+                num_groups: 1,
+                seed: None,
+                accumulation: Accumulation::PerGroup,
+                max_iter: 1000,
+                warmup: 100,
+                step_size: None,
+                escape: None,
+                progress: default_progress(),
+                cancellation: Cancellation::new(),
+            },
+            auto_groups: true,
+        }
+    }
+}
+
+impl<T: Float, S> SettingsBuilder<T, S> {
+    /// Sets the generator used for sampling initial points.
+    #[must_use]
+    #[inline]
+    pub const fn generator(mut self, generator: Generator<T>) -> Self {
+        self.settings.generator = generator;
+        self
+    }
+
+    /// Sets the projection onto the image plane, owned by the settings.
+    #[must_use]
+    #[inline]
+    pub fn projection<P: Projection<T, S> + Send + Sync + 'static>(self, projection: P) -> Self {
+        self.shared_projection(Arc::new(projection))
+    }
+
+    /// Sets the projection onto the image plane, shared with other settings.
+    #[must_use]
+    #[inline]
+    pub fn shared_projection(mut self, projection: Arc<dyn Projection<T, S> + Send + Sync>) -> Self {
+        self.settings.projection = projection;
+        self
+    }
+
+    /// Sets the resolution of the output image [height, width].
+    #[must_use]
+    #[inline]
+    pub const fn resolution(mut self, resolution: [usize; 2]) -> Self {
+        self.settings.resolution = resolution;
+        self
+    }
+
+    /// Sets the offset of the rendering viewport in the complex plane [real, imag].
+    #[must_use]
+    #[inline]
+    pub const fn offset(mut self, offset: [T; 2]) -> Self {
+        self.settings.offset = offset;
+        self
+    }
+
+    /// Sets the height of the rendering viewport.
+    #[must_use]
+    #[inline]
+    pub const fn scale(mut self, scale: T) -> Self {
+        self.settings.scale = scale;
+        self
+    }
+
+    /// Sets the number of sample points to generate.
+    #[must_use]
+    #[inline]
+    pub const fn num_samples(mut self, num_samples: usize) -> Self {
+        self.settings.num_samples = num_samples;
+        self
+    }
+
+    /// Sets the number of parallel groups, instead of choosing it from the rayon thread pool.
+    #[must_use]
+    #[inline]
+    pub const fn num_groups(mut self, num_groups: usize) -> Self {
+        self.settings.num_groups = num_groups;
+        self.auto_groups = false;
+        self
+    }
+
+    /// Sets the seed for the random sampling of initial points, making renders reproducible.
+    #[must_use]
+    #[inline]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.settings.seed = Some(seed);
+        self
+    }
+
+    /// Sets the strategy for accumulating the visits of the groups.
+    #[must_use]
+    #[inline]
+    pub const fn accumulation(mut self, accumulation: Accumulation) -> Self {
+        self.settings.accumulation = accumulation;
+        self
+    }
+
+    /// Sets the maximum number of iterations per sample point.
+    #[must_use]
+    #[inline]
+    pub const fn max_iter(mut self, max_iter: usize) -> Self {
+        self.settings.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the number of warmup iterations before plotting point positions.
+    #[must_use]
+    #[inline]
+    pub const fn warmup(mut self, warmup: usize) -> Self {
+        self.settings.warmup = warmup;
+        self
+    }
+
+    /// Sets the integration time step, overriding the step size of attractors sampled from continuous flows.
+    #[must_use]
+    #[inline]
+    pub const fn step_size(mut self, step_size: T) -> Self {
+        self.settings.step_size = Some(step_size);
+        self
+    }
+
+    /// Sets the condition on orbits escaping, plotting only escaping or bounded orbits.
+    #[must_use]
+    #[inline]
+    pub const fn escape(mut self, escape: Escape<T>) -> Self {
+        self.settings.escape = Some(escape);
+        self
+    }
+
+    /// Sets the observer of the samples completed, owned by the settings.
+    #[must_use]
+    #[inline]
+    pub fn progress<P: Progress + 'static>(self, progress: P) -> Self {
+        self.shared_progress(Arc::new(progress))
+    }
+
+    /// Sets the observer of the samples completed, shared with other settings.
+    #[must_use]
+    #[inline]
+    pub fn shared_progress(mut self, progress: Arc<dyn Progress>) -> Self {
+        self.settings.progress = progress;
+        self
+    }
+
+    /// Sets the token stopping the render early when cancelled, keeping a clone of the token to cancel it.
+    #[must_use]
+    #[inline]
+    pub fn cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.settings.cancellation = cancellation;
+        self
+    }
+
+    /// Builds the settings, checking them with [`Settings::validate`].
+    ///
+    /// When the number of groups is chosen automatically, it is the number of threads in the rayon pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns the problem reported by [`Settings::validate`] if the settings are invalid.
+    #[inline]
+    pub fn build(mut self) -> Result<Settings<T, S>, Error> {
+        if self.auto_groups {
+            self.settings.num_groups = current_num_threads().max(1);
+        }
+        self.settings.validate()?;
+        Ok(self.settings)
+    }
 }
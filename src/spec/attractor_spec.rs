@@ -0,0 +1,692 @@
+//! Implementation of the serialisable specification of an attractor.
+
+use nalgebra::{Complex, Scalar, Vector3};
+use num_traits::{Float, FloatConst};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::{
+    Affine, Aizawa, Alternate, Arguments, ArnoldCat, Attractor, Bedhead, Chen, Chirikov, Clifford, Compose, DeJong, Duffing,
+    Expression, Gingerbreadman, GumowskiMira, Halvorsen, Henon, Ifs, Ikeda, IntegratorSpec, Julia, Lorenz, LotkaVolterra, Lozi,
+    Mandelbrot, Multibrot, Orbit, Parameter, Perturbed, Pickover, RandomSwitch, Registry, Rossler, SpecError, Svensson,
+    SymmetricIcon, SymmetricQuilt, Thomas, Tinkerbell, Zaslavsky,
+};
+
+/// Attractor built from a specification, tagged by the dimension of its state.
+#[non_exhaustive]
+pub enum BuiltAttractor<T> {
+    /// Attractor evolving points of the complex plane.
+    Planar(Box<dyn Attractor<T> + Send + Sync>),
+    /// Attractor evolving three-dimensional vectors.
+    Spatial(Box<dyn Attractor<T, Vector3<T>> + Send + Sync>),
+    /// Escape-time map evolving orbits which carry their own parameter.
+    Orbit(Box<dyn Attractor<T, Orbit<T>> + Send + Sync>),
+}
+
+/// Attractor nested in a `RandomSwitch` specification, together with its selection weight.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Specification structs are expected to be constructed directly."
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightedSpec<T> {
+    /// Attractor chosen between.
+    pub attractor: AttractorSpec<T>,
+    /// Selection weight of the attractor.
+    pub weight: T,
+}
+
+/// Serialisable specification of an attractor.
+///
+/// Parameters may be swept across the frames of an animation, combinators nest further specifications,
+/// and attractor types defined outside this crate are constructed by name from a `Registry`.
+/// Continuous-time flows are integrated with RK4 at a step size of `0.01`, unless another integrator or step size is given.
+///
+/// Modulated attractors are defined by code rather than data, so they have no specification, but can be made
+/// available to configuration files through a `Registry`. Fractal flames evolve a `FlameState`, which no
+/// `BuiltAttractor` holds, so they can only be rendered from code.
+#[expect(
+    clippy::empty_enum_variants_with_brackets,
+    reason = "Variants without parameters accept both `!Name` and `!Name {}` in configuration files."
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum AttractorSpec<T> {
+    /// An affine map of the plane.
+    Affine {
+        /// Coefficient of `x` in the new `x`.
+        a: Parameter<T>,
+        /// Coefficient of `y` in the new `x`.
+        b: Parameter<T>,
+        /// Translation of `x`.
+        c: Parameter<T>,
+        /// Coefficient of `x` in the new `y`.
+        d: Parameter<T>,
+        /// Coefficient of `y` in the new `y`.
+        e: Parameter<T>,
+        /// Translation of `y`.
+        f: Parameter<T>,
+    },
+    /// The Aizawa flow.
+    Aizawa {
+        /// Parameter 'a' of the system.
+        a: Parameter<T>,
+        /// Parameter 'b' of the system.
+        b: Parameter<T>,
+        /// Parameter 'c' of the system.
+        c: Parameter<T>,
+        /// Parameter 'd' of the system.
+        d: Parameter<T>,
+        /// Parameter 'e' of the system.
+        e: Parameter<T>,
+        /// Parameter 'f' of the system.
+        f: Parameter<T>,
+        /// Integrator advancing the flow, defaulting to RK4.
+        #[serde(default = "IntegratorSpec::default")]
+        integrator: IntegratorSpec<T>,
+        /// Integration time step, defaulting to `0.01`.
+        #[serde(default = "Option::default")]
+        step_size: Option<T>,
+    },
+    /// Alternation between two attractors on even and odd iterations.
+    Alternate {
+        /// Attractor applied on even iterations.
+        first: Box<Self>,
+        /// Attractor applied on odd iterations.
+        second: Box<Self>,
+    },
+    /// Arnold's cat map.
+    ArnoldCat {},
+    /// Barnsley's fern.
+    BarnsleyFern {},
+    /// The Bedhead attractor.
+    Bedhead {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+    },
+    /// The Chen flow.
+    Chen {
+        /// Parameter 'a' of the system.
+        a: Parameter<T>,
+        /// Parameter 'b' of the system.
+        b: Parameter<T>,
+        /// Parameter 'c' of the system.
+        c: Parameter<T>,
+        /// Integrator advancing the flow, defaulting to RK4.
+        #[serde(default = "IntegratorSpec::default")]
+        integrator: IntegratorSpec<T>,
+        /// Integration time step, defaulting to `0.01`.
+        #[serde(default = "Option::default")]
+        step_size: Option<T>,
+    },
+    /// The Chirikov standard map.
+    Chirikov {
+        /// Parameter 'k' of the map.
+        k: Parameter<T>,
+    },
+    /// The Clifford attractor.
+    Clifford {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+        /// Parameter 'c' of the map.
+        c: Parameter<T>,
+        /// Parameter 'd' of the map.
+        d: Parameter<T>,
+    },
+    /// Composition applying an inner and then an outer attractor at each iteration.
+    Compose {
+        /// Attractor applied second.
+        outer: Box<Self>,
+        /// Attractor applied first.
+        inner: Box<Self>,
+    },
+    /// The De Jong attractor.
+    DeJong {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+        /// Parameter 'c' of the map.
+        c: Parameter<T>,
+        /// Parameter 'd' of the map.
+        d: Parameter<T>,
+    },
+    /// The Duffing map.
+    Duffing {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+    },
+    /// Attractor defined by formulae parsed at runtime.
+    Expression {
+        /// Formulae for the variables `x`, `y` and, for spatial expressions, `z`.
+        formulae: Vec<String>,
+        /// Values of the named parameters used by the formulae.
+        #[serde(default = "BTreeMap::new")]
+        parameters: BTreeMap<String, Parameter<T>>,
+        /// Whether the formulae are the time derivatives of a continuous-time flow.
+        #[serde(default)]
+        flow: bool,
+        /// Integrator advancing a flow, defaulting to RK4.
+        #[serde(default = "IntegratorSpec::default")]
+        integrator: IntegratorSpec<T>,
+        /// Integration time step of a flow, defaulting to `0.01`.
+        #[serde(default = "Option::default")]
+        step_size: Option<T>,
+    },
+    /// The Gingerbreadman map.
+    Gingerbreadman {},
+    /// The Gumowski-Mira map.
+    GumowskiMira {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+        /// Parameter 'mu' of the map.
+        mu: Parameter<T>,
+    },
+    /// The Halvorsen flow.
+    Halvorsen {
+        /// Parameter 'a' of the system.
+        a: Parameter<T>,
+        /// Integrator advancing the flow, defaulting to RK4.
+        #[serde(default = "IntegratorSpec::default")]
+        integrator: IntegratorSpec<T>,
+        /// Integration time step, defaulting to `0.01`.
+        #[serde(default = "Option::default")]
+        step_size: Option<T>,
+    },
+    /// The Heighway dragon.
+    HeighwayDragon {},
+    /// The Henon map.
+    Henon {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+    },
+    /// Iterated function system given as a table of affine maps.
+    Ifs {
+        /// Rows `[a, b, c, d, e, f, p]` of the conventional IFS table layout.
+        maps: Vec<[T; 7]>,
+    },
+    /// The Ikeda map.
+    Ikeda {
+        /// Parameter 'u' of the map.
+        u: Parameter<T>,
+    },
+    /// The Julia map.
+    Julia {
+        /// Real part of the parameter 'c' of the map.
+        c_re: Parameter<T>,
+        /// Imaginary part of the parameter 'c' of the map.
+        c_im: Parameter<T>,
+    },
+    /// The Lorenz flow.
+    Lorenz {
+        /// Parameter 'sigma' of the system.
+        sigma: Parameter<T>,
+        /// Parameter 'rho' of the system.
+        rho: Parameter<T>,
+        /// Parameter 'beta' of the system.
+        beta: Parameter<T>,
+        /// Integrator advancing the flow, defaulting to RK4.
+        #[serde(default = "IntegratorSpec::default")]
+        integrator: IntegratorSpec<T>,
+        /// Integration time step, defaulting to `0.01`.
+        #[serde(default = "Option::default")]
+        step_size: Option<T>,
+    },
+    /// The discrete Lotka-Volterra map.
+    LotkaVolterra {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+    },
+    /// The Lozi map.
+    Lozi {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+    },
+    /// The Mandelbrot map, evolving orbits which start at zero with the sampled parameter.
+    Mandelbrot {},
+    /// The Multibrot map of the given degree, evolving orbits which start at zero with the sampled parameter.
+    Multibrot {
+        /// Exponent of the map, which must be at least two.
+        degree: i32,
+    },
+    /// Attractor with Gaussian noise added to each iteration.
+    Perturbed {
+        /// Attractor being perturbed.
+        attractor: Box<Self>,
+        /// Standard deviation of the noise.
+        std_dev: Parameter<T>,
+    },
+    /// The Pickover attractor.
+    Pickover {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+        /// Parameter 'c' of the map.
+        c: Parameter<T>,
+        /// Parameter 'd' of the map.
+        d: Parameter<T>,
+    },
+    /// Random switching between attractors in proportion to their weights.
+    RandomSwitch {
+        /// Attractors chosen between, with their selection weights.
+        attractors: Vec<WeightedSpec<T>>,
+    },
+    /// Attractor constructed by name from a `Registry`.
+    Registered {
+        /// Name under which the attractor is registered.
+        name: String,
+        /// Values of the parameters passed to its constructor.
+        #[serde(default = "BTreeMap::new")]
+        parameters: BTreeMap<String, Parameter<T>>,
+    },
+    /// The Rossler flow.
+    Rossler {
+        /// Parameter 'a' of the system.
+        a: Parameter<T>,
+        /// Parameter 'b' of the system.
+        b: Parameter<T>,
+        /// Parameter 'c' of the system.
+        c: Parameter<T>,
+        /// Integrator advancing the flow, defaulting to RK4.
+        #[serde(default = "IntegratorSpec::default")]
+        integrator: IntegratorSpec<T>,
+        /// Integration time step, defaulting to `0.01`.
+        #[serde(default = "Option::default")]
+        step_size: Option<T>,
+    },
+    /// The Sierpinski triangle.
+    Sierpinski {},
+    /// The Svensson attractor.
+    Svensson {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+        /// Parameter 'c' of the map.
+        c: Parameter<T>,
+        /// Parameter 'd' of the map.
+        d: Parameter<T>,
+    },
+    /// The symmetric icon attractor of Field and Golubitsky.
+    SymmetricIcon {
+        /// Parameter 'lambda' of the map.
+        lambda: Parameter<T>,
+        /// Parameter 'alpha' of the map.
+        alpha: Parameter<T>,
+        /// Parameter 'beta' of the map.
+        beta: Parameter<T>,
+        /// Parameter 'gamma' of the map.
+        gamma: Parameter<T>,
+        /// Parameter 'omega' of the map.
+        omega: Parameter<T>,
+        /// Degree of rotational symmetry, which must be positive.
+        degree: i32,
+    },
+    /// The symmetric quilt attractor of Field and Golubitsky.
+    SymmetricQuilt {
+        /// Parameter 'lambda' of the map.
+        lambda: Parameter<T>,
+        /// Parameter 'alpha' of the map.
+        alpha: Parameter<T>,
+        /// Parameter 'beta' of the map.
+        beta: Parameter<T>,
+        /// Parameter 'gamma' of the map.
+        gamma: Parameter<T>,
+        /// Parameter 'omega' of the map.
+        omega: Parameter<T>,
+        /// Parameter 'm' of the map.
+        m: Parameter<T>,
+        /// Parameter 'shift' of the map.
+        shift: Parameter<T>,
+    },
+    /// Thomas' cyclically symmetric flow.
+    Thomas {
+        /// Parameter 'b' of the system.
+        b: Parameter<T>,
+        /// Integrator advancing the flow, defaulting to RK4.
+        #[serde(default = "IntegratorSpec::default")]
+        integrator: IntegratorSpec<T>,
+        /// Integration time step, defaulting to `0.01`.
+        #[serde(default = "Option::default")]
+        step_size: Option<T>,
+    },
+    /// The Tinkerbell map.
+    Tinkerbell {
+        /// Parameter 'a' of the map.
+        a: Parameter<T>,
+        /// Parameter 'b' of the map.
+        b: Parameter<T>,
+        /// Parameter 'c' of the map.
+        c: Parameter<T>,
+        /// Parameter 'd' of the map.
+        d: Parameter<T>,
+    },
+    /// The Zaslavsky map.
+    Zaslavsky {
+        /// Parameter 'epsilon' of the map.
+        epsilon: Parameter<T>,
+        /// Parameter 'nu' of the map.
+        nu: Parameter<T>,
+        /// Parameter 'r' of the map.
+        r: Parameter<T>,
+    },
+}
+
+impl<T: 'static + Send + Sync + Float + FloatConst + Scalar> AttractorSpec<T> {
+    /// Builds the specified attractor for frame `index` of `total`, constructing registered attractors from `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a registered attractor is unknown or fails to construct, an expression is invalid,
+    /// combined attractors have states of different dimensions, an escape-time map is perturbed,
+    /// the weights of an IFS or random switch are invalid, or the integration parameters of a flow are not positive.
+    ///
+    /// # Panics
+    ///
+    /// This function will not panic.
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Borrowing the nested specifications requires matching through the reference."
+    )]
+    #[expect(clippy::too_many_lines, reason = "Each arm builds one attractor type.")]
+    #[inline]
+    pub fn build(&self, index: usize, total: usize, registry: &Registry<T>) -> Result<BuiltAttractor<T>, SpecError> {
+        Ok(match self {
+            Self::Affine { a, b, c, d, e, f } => BuiltAttractor::Planar(Box::new(Affine::new(
+                a.value(index, total),
+                b.value(index, total),
+                c.value(index, total),
+                d.value(index, total),
+                e.value(index, total),
+                f.value(index, total),
+            ))),
+            Self::Aizawa {
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+                integrator,
+                step_size,
+            } => BuiltAttractor::Spatial(integrator.flow_map(
+                Aizawa::new(
+                    a.value(index, total),
+                    b.value(index, total),
+                    c.value(index, total),
+                    d.value(index, total),
+                    e.value(index, total),
+                    f.value(index, total),
+                ),
+                *step_size,
+            )?),
+            Self::Alternate { first, second } => {
+                match (first.build(index, total, registry)?, second.build(index, total, registry)?) {
+                    (BuiltAttractor::Planar(a), BuiltAttractor::Planar(b)) => {
+                        BuiltAttractor::Planar(Box::new(Alternate::new(a, b)))
+                    }
+                    (BuiltAttractor::Spatial(a), BuiltAttractor::Spatial(b)) => {
+                        BuiltAttractor::Spatial(Box::new(Alternate::new(a, b)))
+                    }
+                    (BuiltAttractor::Orbit(a), BuiltAttractor::Orbit(b)) => {
+                        BuiltAttractor::Orbit(Box::new(Alternate::new(a, b)))
+                    }
+                    (BuiltAttractor::Planar(_) | BuiltAttractor::Spatial(_) | BuiltAttractor::Orbit(_), _) => {
+                        return Err(SpecError::DimensionMismatch);
+                    }
+                }
+            }
+            Self::ArnoldCat {} => BuiltAttractor::Planar(Box::new(ArnoldCat::new())),
+            Self::BarnsleyFern {} => BuiltAttractor::Planar(Box::new(Ifs::barnsley_fern())),
+            Self::Bedhead { a, b } => {
+                BuiltAttractor::Planar(Box::new(Bedhead::new(a.value(index, total), b.value(index, total))))
+            }
+            Self::Chen {
+                a,
+                b,
+                c,
+                integrator,
+                step_size,
+            } => BuiltAttractor::Spatial(integrator.flow_map(
+                Chen::new(a.value(index, total), b.value(index, total), c.value(index, total)),
+                *step_size,
+            )?),
+            Self::Chirikov { k } => BuiltAttractor::Planar(Box::new(Chirikov::new(k.value(index, total)))),
+            Self::Clifford { a, b, c, d } => BuiltAttractor::Planar(Box::new(Clifford::new(
+                a.value(index, total),
+                b.value(index, total),
+                c.value(index, total),
+                d.value(index, total),
+            ))),
+            Self::Compose { outer, inner } => {
+                match (outer.build(index, total, registry)?, inner.build(index, total, registry)?) {
+                    (BuiltAttractor::Planar(a), BuiltAttractor::Planar(b)) => {
+                        BuiltAttractor::Planar(Box::new(Compose::new(a, b)))
+                    }
+                    (BuiltAttractor::Spatial(a), BuiltAttractor::Spatial(b)) => {
+                        BuiltAttractor::Spatial(Box::new(Compose::new(a, b)))
+                    }
+                    (BuiltAttractor::Orbit(a), BuiltAttractor::Orbit(b)) => BuiltAttractor::Orbit(Box::new(Compose::new(a, b))),
+                    (BuiltAttractor::Planar(_) | BuiltAttractor::Spatial(_) | BuiltAttractor::Orbit(_), _) => {
+                        return Err(SpecError::DimensionMismatch);
+                    }
+                }
+            }
+            Self::DeJong { a, b, c, d } => BuiltAttractor::Planar(Box::new(DeJong::new(
+                a.value(index, total),
+                b.value(index, total),
+                c.value(index, total),
+                d.value(index, total),
+            ))),
+            Self::Duffing { a, b } => {
+                BuiltAttractor::Planar(Box::new(Duffing::new(a.value(index, total), b.value(index, total))))
+            }
+            Self::Expression {
+                formulae,
+                parameters,
+                flow,
+                integrator,
+                step_size,
+            } => {
+                let sources: Vec<&str> = formulae.iter().map(String::as_str).collect();
+                let values: Vec<(&str, T)> = parameters
+                    .iter()
+                    .map(|(name, parameter)| (name.as_str(), parameter.value(index, total)))
+                    .collect();
+                match (sources.len(), *flow) {
+                    (2, false) => BuiltAttractor::Planar(Box::new(Expression::new(&["x", "y"], &sources, &values)?)),
+                    (3, false) => BuiltAttractor::Spatial(Box::new(Expression::new(&["x", "y", "z"], &sources, &values)?)),
+                    (3, true) => BuiltAttractor::Spatial(
+                        integrator.flow_map(Expression::new(&["x", "y", "z"], &sources, &values)?, *step_size)?,
+                    ),
+                    (count, _) => return Err(SpecError::FormulaCount(count)),
+                }
+            }
+            Self::Gingerbreadman {} => BuiltAttractor::Planar(Box::new(Gingerbreadman::new())),
+            Self::GumowskiMira { a, b, mu } => BuiltAttractor::Planar(Box::new(GumowskiMira::new(
+                a.value(index, total),
+                b.value(index, total),
+                mu.value(index, total),
+            ))),
+            Self::Halvorsen {
+                a,
+                integrator,
+                step_size,
+            } => BuiltAttractor::Spatial(integrator.flow_map(Halvorsen::new(a.value(index, total)), *step_size)?),
+            Self::HeighwayDragon {} => BuiltAttractor::Planar(Box::new(Ifs::heighway_dragon())),
+            Self::Henon { a, b } => BuiltAttractor::Planar(Box::new(Henon::new(a.value(index, total), b.value(index, total)))),
+            Self::Ifs { maps } => {
+                validate_weights(maps.iter().map(|row| row[6]))?;
+                BuiltAttractor::Planar(Box::new(Ifs::from_table(maps)))
+            }
+            Self::Ikeda { u } => BuiltAttractor::Planar(Box::new(Ikeda::new(u.value(index, total)))),
+            Self::Julia { c_re, c_im } => BuiltAttractor::Planar(Box::new(Julia::new(Complex::new(
+                c_re.value(index, total),
+                c_im.value(index, total),
+            )))),
+            Self::Lorenz {
+                sigma,
+                rho,
+                beta,
+                integrator,
+                step_size,
+            } => BuiltAttractor::Spatial(integrator.flow_map(
+                Lorenz::new(sigma.value(index, total), rho.value(index, total), beta.value(index, total)),
+                *step_size,
+            )?),
+            Self::LotkaVolterra { a, b } => {
+                BuiltAttractor::Planar(Box::new(LotkaVolterra::new(a.value(index, total), b.value(index, total))))
+            }
+            Self::Lozi { a, b } => BuiltAttractor::Planar(Box::new(Lozi::new(a.value(index, total), b.value(index, total)))),
+            Self::Mandelbrot {} => BuiltAttractor::Orbit(Box::new(Mandelbrot::new())),
+            Self::Multibrot { degree } => {
+                if *degree < 2 {
+                    return Err(SpecError::InvalidDegree(*degree));
+                }
+                BuiltAttractor::Orbit(Box::new(Multibrot::new(*degree)))
+            }
+            Self::Perturbed { attractor, std_dev } => {
+                let deviation = std_dev.value(index, total);
+                match attractor.build(index, total, registry)? {
+                    BuiltAttractor::Planar(a) => BuiltAttractor::Planar(Box::new(Perturbed::new(a, deviation))),
+                    BuiltAttractor::Spatial(a) => BuiltAttractor::Spatial(Box::new(Perturbed::new(a, deviation))),
+                    BuiltAttractor::Orbit(_) => return Err(SpecError::UnsupportedOrbit("Perturbed".to_owned())),
+                }
+            }
+            Self::Pickover { a, b, c, d } => BuiltAttractor::Planar(Box::new(Pickover::new(
+                a.value(index, total),
+                b.value(index, total),
+                c.value(index, total),
+                d.value(index, total),
+            ))),
+            Self::RandomSwitch { attractors } => {
+                validate_weights(attractors.iter().map(|weighted| weighted.weight))?;
+                let mut planar = Vec::new();
+                let mut spatial = Vec::new();
+                let mut orbit = Vec::new();
+                for weighted in attractors {
+                    match weighted.attractor.build(index, total, registry)? {
+                        BuiltAttractor::Planar(a) => planar.push((a, weighted.weight)),
+                        BuiltAttractor::Spatial(a) => spatial.push((a, weighted.weight)),
+                        BuiltAttractor::Orbit(a) => orbit.push((a, weighted.weight)),
+                    }
+                }
+                match (planar.is_empty(), spatial.is_empty(), orbit.is_empty()) {
+                    (false, true, true) => BuiltAttractor::Planar(Box::new(RandomSwitch::new(planar))),
+                    (true, false, true) => BuiltAttractor::Spatial(Box::new(RandomSwitch::new(spatial))),
+                    (true, true, false) => BuiltAttractor::Orbit(Box::new(RandomSwitch::new(orbit))),
+                    _ => return Err(SpecError::DimensionMismatch),
+                }
+            }
+            Self::Registered { name, parameters } => {
+                let values = parameters
+                    .iter()
+                    .map(|(parameter, value)| (parameter.clone(), value.value(index, total)))
+                    .collect();
+                registry.build(name, &Arguments::new(values))?
+            }
+            Self::Rossler {
+                a,
+                b,
+                c,
+                integrator,
+                step_size,
+            } => BuiltAttractor::Spatial(integrator.flow_map(
+                Rossler::new(a.value(index, total), b.value(index, total), c.value(index, total)),
+                *step_size,
+            )?),
+            Self::Sierpinski {} => BuiltAttractor::Planar(Box::new(Ifs::sierpinski())),
+            Self::Svensson { a, b, c, d } => BuiltAttractor::Planar(Box::new(Svensson::new(
+                a.value(index, total),
+                b.value(index, total),
+                c.value(index, total),
+                d.value(index, total),
+            ))),
+            Self::SymmetricIcon {
+                lambda,
+                alpha,
+                beta,
+                gamma,
+                omega,
+                degree,
+            } => {
+                if *degree < 1 {
+                    return Err(SpecError::NonPositiveParameter("degree".to_owned()));
+                }
+                BuiltAttractor::Planar(Box::new(SymmetricIcon::new(
+                    lambda.value(index, total),
+                    alpha.value(index, total),
+                    beta.value(index, total),
+                    gamma.value(index, total),
+                    omega.value(index, total),
+                    *degree,
+                )))
+            }
+            Self::SymmetricQuilt {
+                lambda,
+                alpha,
+                beta,
+                gamma,
+                omega,
+                m,
+                shift,
+            } => BuiltAttractor::Planar(Box::new(SymmetricQuilt::new(
+                lambda.value(index, total),
+                alpha.value(index, total),
+                beta.value(index, total),
+                gamma.value(index, total),
+                omega.value(index, total),
+                m.value(index, total),
+                shift.value(index, total),
+            ))),
+            Self::Thomas {
+                b,
+                integrator,
+                step_size,
+            } => BuiltAttractor::Spatial(integrator.flow_map(Thomas::new(b.value(index, total)), *step_size)?),
+            Self::Tinkerbell { a, b, c, d } => BuiltAttractor::Planar(Box::new(Tinkerbell::new(
+                a.value(index, total),
+                b.value(index, total),
+                c.value(index, total),
+                d.value(index, total),
+            ))),
+            Self::Zaslavsky { epsilon, nu, r } => BuiltAttractor::Planar(Box::new(Zaslavsky::new(
+                epsilon.value(index, total),
+                nu.value(index, total),
+                r.value(index, total),
+            ))),
+        })
+    }
+}
+
+/// Checks that weights are non-negative with a positive sum, so a weighted choice can be made between them.
+#[inline]
+fn validate_weights<T: Float>(weights: impl Iterator<Item = T>) -> Result<(), SpecError> {
+    let mut sum = T::zero();
+    for weight in weights {
+        if weight.is_nan() || weight < T::zero() {
+            return Err(SpecError::InvalidWeights);
+        }
+        sum = sum + weight;
+    }
+    if sum > T::zero() {
+        Ok(())
+    } else {
+        Err(SpecError::InvalidWeights)
+    }
+}
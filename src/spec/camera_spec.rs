@@ -0,0 +1,105 @@
+//! Implementation of the serialisable specification of a camera.
+
+use nalgebra::{Scalar, Vector3};
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+use crate::{Camera, Parameter, SpecError, error::is_positive};
+
+/// Rotation of a camera within the plane spanned by two axes.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Specification structs are expected to be constructed directly."
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RotationSpec<T> {
+    /// Pair of axes spanning the rotation plane.
+    pub axes: [usize; 2],
+    /// Rotation angle in radians.
+    pub angle: Parameter<T>,
+}
+
+/// Serialisable specification of a three-dimensional `Camera`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CameraSpec<T> {
+    /// Orthographic camera about a centre, rotated in turn by each rotation.
+    Orthographic {
+        /// Point appearing at the centre of the image.
+        centre: [T; 3],
+        /// Rotations applied to the camera, in order.
+        rotations: Vec<RotationSpec<T>>,
+    },
+    /// Perspective camera about a centre, rotated in turn by each rotation.
+    Perspective {
+        /// Point appearing at the centre of the image.
+        centre: [T; 3],
+        /// Distance from the eye to the centre.
+        distance: T,
+        /// Rotations applied to the camera, in order.
+        rotations: Vec<RotationSpec<T>>,
+    },
+    /// Perspective camera placed at an eye and looking at a target.
+    LookAt {
+        /// Position of the eye.
+        eye: [T; 3],
+        /// Point appearing at the centre of the image.
+        target: [T; 3],
+        /// Direction appearing upwards in the image.
+        up: [T; 3],
+    },
+}
+
+impl<T: Float + Scalar> CameraSpec<T> {
+    /// Builds the specified `Camera` for frame `index` of `total`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a rotation has an axis outside the three-dimensional state, or two equal axes,
+    /// if a perspective distance is not positive, or if a look-at camera has its eye at its target or its up
+    /// direction parallel to the view direction.
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Borrowing the rotations requires matching through the reference."
+    )]
+    #[inline]
+    pub fn build(&self, index: usize, total: usize) -> Result<Camera<T, 3>, SpecError> {
+        let rotate = |camera: Camera<T, 3>, rotations: &[RotationSpec<T>]| {
+            rotations.iter().try_fold(camera, |rotated, rotation| {
+                let [i, j] = rotation.axes;
+                if i >= 3 || j >= 3 || i == j {
+                    return Err(SpecError::InvalidRotation(rotation.axes));
+                }
+                Ok(rotated.rotate(i, j, rotation.angle.value(index, total)))
+            })
+        };
+        match self {
+            Self::Orthographic { centre, rotations } => rotate(Camera::orthographic(Vector3::from(*centre)), rotations),
+            Self::Perspective {
+                centre,
+                distance,
+                rotations,
+            } => {
+                if !is_positive(*distance) {
+                    return Err(SpecError::NonPositiveParameter("distance".to_owned()));
+                }
+                rotate(Camera::perspective(Vector3::from(*centre), *distance), rotations)
+            }
+            Self::LookAt { eye, target, up } => {
+                // The up direction must keep a component perpendicular to a non-zero view direction
+                let view = [target[0] - eye[0], target[1] - eye[1], target[2] - eye[2]];
+                let perpendicular = (view[1] * up[2] - view[2] * up[1])
+                    .hypot(view[2] * up[0] - view[0] * up[2])
+                    .hypot(view[0] * up[1] - view[1] * up[0]);
+                if !is_positive(perpendicular) {
+                    return Err(SpecError::DegenerateCamera);
+                }
+                Ok(Camera::look_at(
+                    Vector3::from(*eye),
+                    Vector3::from(*target),
+                    Vector3::from(*up),
+                ))
+            }
+        }
+    }
+}
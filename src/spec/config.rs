@@ -0,0 +1,173 @@
+//! Implementation of the serialisable configuration of a render.
+
+use nalgebra::{Scalar, Vector3};
+use num_traits::{Float, FloatConst};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::{
+    Accumulation, Attractor, AttractorSpec, BuiltAttractor, Camera, CameraSpec, Error, Escape, Generator, GeneratorSpec,
+    Planar, Projection, Registry, Settings, SettingsBuilder, SpecError,
+};
+
+/// Configuration of the image plane.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Configuration structs are expected to be constructed directly."
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderingConfig<T> {
+    /// Resolution of the output image [height, width].
+    pub resolution: [usize; 2],
+    /// Offset of the rendering viewport in the complex plane [real, imag].
+    pub offset: [T; 2],
+    /// Scale factor for the rendering viewport (scales imaginary axis directly, real axis is scaled by the aspect ratio set by `resolution`).
+    pub scale: T,
+    /// Camera projecting three-dimensional attractors onto the image plane.
+    pub camera: Option<CameraSpec<T>>,
+}
+
+/// Configuration of checkpointing for long renders.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Configuration structs are expected to be constructed directly."
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointConfig {
+    /// Checkpoint file to resume from and periodically write to.
+    pub path: String,
+    /// Number of samples rendered between checkpoints.
+    pub batch_size: usize,
+}
+
+/// Configuration of the sampling and parallelism of a render.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Configuration structs are expected to be constructed directly."
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessingConfig {
+    /// Number of sample points to generate.
+    pub num_samples: usize,
+    /// Number of parallel groups for multi-threaded rendering.
+    pub num_groups: usize,
+    /// Seed for the random sampling of initial points, making renders reproducible when set.
+    pub seed: Option<u64>,
+    /// Strategy for accumulating the visits of the groups, defaulting to one image per group.
+    #[serde(default)]
+    pub accumulation: Accumulation,
+    /// Checkpointing of the accumulated density.
+    pub checkpoint: Option<CheckpointConfig>,
+}
+
+/// Configuration of the iteration of each orbit.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Configuration structs are expected to be constructed directly."
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationConfig<T> {
+    /// Maximum number of iterations per sample point.
+    pub max_iter: usize,
+    /// Number of warmup iterations before plotting point positions.
+    pub warmup: usize,
+    /// Integration time step for attractors sampled from continuous flows, overriding their own step size when set.
+    pub step_size: Option<T>,
+    /// Condition on whether an orbit escapes, plotting every orbit when unset.
+    pub escape: Option<Escape<T>>,
+}
+
+/// Complete serialisable configuration of a render, or of each frame of an animation.
+///
+/// Configuration files may contain further sections, such as post-processing settings, which are ignored here.
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "Configuration structs are expected to be constructed directly."
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderConfig<T> {
+    /// Attractor being rendered.
+    pub attractor: AttractorSpec<T>,
+    /// Generator used for sampling initial points.
+    pub generator: GeneratorSpec<T>,
+    /// Number of frames to render, across which swept parameters vary.
+    pub num_frames: usize,
+    /// Image plane settings.
+    pub rendering: RenderingConfig<T>,
+    /// Sampling and parallelism settings.
+    pub processing: ProcessingConfig,
+    /// Orbit iteration settings.
+    pub simulation: SimulationConfig<T>,
+}
+
+impl<T: 'static + Send + Sync + Float + FloatConst + Scalar> RenderConfig<T> {
+    /// Builds the generator used for sampling initial points.
+    #[must_use]
+    #[inline]
+    pub const fn generator(&self) -> Generator<T> {
+        self.generator.build()
+    }
+
+    /// Builds the attractor of the given frame, constructing registered attractors from `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attractor specification cannot be built.
+    #[inline]
+    pub fn attractor(&self, frame: usize, registry: &Registry<T>) -> Result<BuiltAttractor<T>, SpecError> {
+        self.attractor.build(frame, self.num_frames, registry)
+    }
+
+    /// Builds the camera of the given frame, defaulting to an orthographic view along the third axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the camera specification cannot be built.
+    #[inline]
+    pub fn camera(&self, frame: usize) -> Result<Camera<T, 3>, SpecError> {
+        self.rendering.camera.as_ref().map_or_else(
+            || Ok(Camera::orthographic(Vector3::zeros())),
+            |camera| camera.build(frame, self.num_frames),
+        )
+    }
+
+    /// Assembles render `Settings` from this configuration and the built components of a frame.
+    ///
+    /// The settings are built with a [`SettingsBuilder`], so progress is reported with its default and they are
+    /// checked with [`Settings::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the problem reported by [`Settings::validate`] if the configured settings are invalid.
+    #[inline]
+    pub fn settings<S: 'static>(
+        &self,
+        attractor: Box<dyn Attractor<T, S> + Send + Sync>,
+        projection: Box<dyn Projection<T, S> + Send + Sync>,
+    ) -> Result<Settings<T, S>, Error>
+    where
+        Planar: Projection<T, S>,
+    {
+        let mut builder = SettingsBuilder::shared(Arc::from(attractor))
+            .generator(self.generator())
+            .shared_projection(Arc::from(projection))
+            .resolution(self.rendering.resolution)
+            .offset(self.rendering.offset)
+            .scale(self.rendering.scale)
+            .num_samples(self.processing.num_samples)
+            .num_groups(self.processing.num_groups)
+            .accumulation(self.processing.accumulation)
+            .max_iter(self.simulation.max_iter)
+            .warmup(self.simulation.warmup);
+        if let Some(seed) = self.processing.seed {
+            builder = builder.seed(seed);
+        }
+        if let Some(step_size) = self.simulation.step_size {
+            builder = builder.step_size(step_size);
+        }
+        if let Some(escape) = self.simulation.escape {
+            builder = builder.escape(escape);
+        }
+        builder.build()
+    }
+}
@@ -0,0 +1,100 @@
+//! Implementation of the error produced when building from a specification.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::ExpressionError;
+
+/// Error produced when a specification cannot be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SpecError {
+    /// No attractor is registered under the name.
+    UnknownAttractor(String),
+    /// A registered attractor was not given a value for the named parameter.
+    MissingParameter(String),
+    /// The formulae of an expression could not be compiled.
+    Expression(ExpressionError),
+    /// An expression has a number of formulae other than two for planar maps, or three for spatial maps and flows.
+    FormulaCount(usize),
+    /// Combined attractors have states of different dimensions.
+    DimensionMismatch,
+    /// Weighted choices have no options, a negative weight, or weights summing to zero.
+    InvalidWeights,
+    /// A camera rotation has an axis outside the state, or rotates an axis onto itself.
+    InvalidRotation([usize; 2]),
+    /// The named parameter must be positive.
+    NonPositiveParameter(String),
+    /// The named attractor cannot wrap escape-time maps, which evolve an `Orbit`.
+    UnsupportedOrbit(String),
+    /// A look-at camera has its eye at its target, or its up direction parallel to the view direction.
+    DegenerateCamera,
+    /// A Multibrot map has a degree less than two.
+    InvalidDegree(i32),
+}
+
+impl Display for SpecError {
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Borrowing the names and nested error requires matching through the reference."
+    )]
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownAttractor(name) => write!(f, "No attractor is registered as '{name}'"),
+            Self::MissingParameter(name) => write!(f, "Missing value for parameter '{name}'"),
+            Self::Expression(error) => write!(f, "Invalid expression: {error}"),
+            Self::FormulaCount(count) => write!(
+                f,
+                "Expressions need two formulae for planar maps or three for spatial maps and flows, but found {count}"
+            ),
+            Self::DimensionMismatch => write!(f, "Combined attractors must have states of the same dimension"),
+            Self::InvalidWeights => write!(f, "Weights must be non-negative, with at least one positive weight"),
+            Self::InvalidRotation([i, j]) => {
+                write!(
+                    f,
+                    "Rotation axes {i} and {j} must be distinct axes of the three-dimensional state"
+                )
+            }
+            Self::NonPositiveParameter(name) => write!(f, "Parameter '{name}' must be positive"),
+            Self::UnsupportedOrbit(name) => write!(f, "'{name}' attractors cannot wrap escape-time maps"),
+            Self::DegenerateCamera => write!(
+                f,
+                "Camera eye and target must be distinct, with the up direction not parallel to the view direction"
+            ),
+            Self::InvalidDegree(degree) => write!(f, "Multibrot degree must be at least two, but is {degree}"),
+        }
+    }
+}
+
+impl Error for SpecError {
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "Borrowing the nested error requires matching through the reference."
+    )]
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Expression(error) => Some(error),
+            Self::UnknownAttractor(_)
+            | Self::MissingParameter(_)
+            | Self::FormulaCount(_)
+            | Self::DimensionMismatch
+            | Self::InvalidWeights
+            | Self::InvalidRotation(_)
+            | Self::NonPositiveParameter(_)
+            | Self::UnsupportedOrbit(_)
+            | Self::DegenerateCamera
+            | Self::InvalidDegree(_) => None,
+        }
+    }
+}
+
+impl From<ExpressionError> for SpecError {
+    #[inline]
+    fn from(error: ExpressionError) -> Self {
+        Self::Expression(error)
+    }
+}
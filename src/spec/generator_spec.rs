@@ -0,0 +1,58 @@
+//! Implementation of the serialisable specification of a generator.
+
+use nalgebra::Complex;
+use serde::{Deserialize, Serialize};
+
+use crate::Generator;
+
+/// Serialisable specification of a `Generator`, with points of the complex plane given as `[real, imag]`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum GeneratorSpec<T> {
+    /// Samples points uniformly from an axis-aligned bounding box.
+    Aabb {
+        /// Centre of the bounding box.
+        centre: [T; 2],
+        /// Half of the width and height of the bounding box.
+        half_size: [T; 2],
+    },
+    /// Samples points uniformly from a circular region.
+    Circle {
+        /// Centre of the circle.
+        centre: [T; 2],
+        /// Radius of the circle.
+        radius: T,
+    },
+    /// Samples points from a Gaussian distribution.
+    Gaussian {
+        /// Centre of the Gaussian distribution.
+        centre: [T; 2],
+        /// Standard deviation of the Gaussian distribution.
+        std_dev: T,
+    },
+}
+
+impl<T: Copy> GeneratorSpec<T> {
+    /// Builds the specified `Generator`.
+    #[must_use]
+    #[inline]
+    pub const fn build(&self) -> Generator<T> {
+        match *self {
+            Self::Aabb {
+                centre: [x, y],
+                half_size: [width, height],
+            } => Generator::Aabb {
+                centre: Complex::new(x, y),
+                half_size: Complex::new(width, height),
+            },
+            Self::Circle { centre: [x, y], radius } => Generator::Circle {
+                centre: Complex::new(x, y),
+                radius,
+            },
+            Self::Gaussian { centre: [x, y], std_dev } => Generator::Gaussian {
+                centre: Complex::new(x, y),
+                std_dev,
+            },
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Implementation of the serialisable specification of an integrator.
+
+use nalgebra::{Scalar, Vector3};
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+use crate::{Attractor, DormandPrince, Flow, FlowMap, Rk4, SpecError, error::is_positive};
+
+/// Default integration time step for continuous-time attractors.
+const DEFAULT_STEP_SIZE: f64 = 0.01;
+
+/// Serialisable specification of the `Integrator` advancing a continuous-time flow.
+///
+/// The symplectic `Leapfrog` integrator is not available, as it requires states of even dimension.
+#[expect(
+    clippy::empty_enum_variants_with_brackets,
+    reason = "Variants without parameters accept both `!Name` and `!Name {}` in configuration files."
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum IntegratorSpec<T> {
+    /// Classical fixed-step fourth-order Runge-Kutta.
+    Rk4 {},
+    /// Adaptive fifth-order Runge-Kutta with error control.
+    DormandPrince {
+        /// Combined absolute and relative error tolerance per sub-step.
+        tolerance: T,
+    },
+}
+
+impl<T> Default for IntegratorSpec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::Rk4 {}
+    }
+}
+
+impl<T: 'static + Send + Sync + Float + Scalar> IntegratorSpec<T> {
+    /// Builds a `FlowMap` advancing `flow` with the specified integrator by `step_size`, or by `0.01` when unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the step size or the tolerance is not positive.
+    ///
+    /// # Panics
+    ///
+    /// This function will not panic.
+    #[inline]
+    pub fn flow_map<F: 'static + Send + Sync + Flow<T, 3>>(
+        &self,
+        flow: F,
+        step_size: Option<T>,
+    ) -> Result<Box<dyn Attractor<T, Vector3<T>> + Send + Sync>, SpecError> {
+        let dt = step_size.unwrap_or_else(|| T::from(DEFAULT_STEP_SIZE).unwrap());
+        if !is_positive(dt) {
+            return Err(SpecError::NonPositiveParameter("step_size".to_owned()));
+        }
+        Ok(match *self {
+            Self::Rk4 {} => Box::new(FlowMap::new(flow, Rk4::new(), dt)),
+            Self::DormandPrince { tolerance } => {
+                if !is_positive(tolerance) {
+                    return Err(SpecError::NonPositiveParameter("tolerance".to_owned()));
+                }
+                Box::new(FlowMap::new(flow, DormandPrince::new(tolerance), dt))
+            }
+        })
+    }
+}
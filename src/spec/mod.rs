@@ -0,0 +1,24 @@
+//! Module defining serialisable specifications of renders, available with the `serde` feature.
+//!
+//! Specifications describe attractors, generators, cameras and render settings as plain data,
+//! so a configuration file fully defines a render. Attractor parameters may be fixed or swept
+//! across the frames of an animation, and attractor types defined outside this crate can be
+//! referred to by name through a [`Registry`].
+
+mod attractor_spec;
+mod camera_spec;
+mod config;
+mod error;
+mod generator_spec;
+mod integrator_spec;
+mod parameter;
+mod registry;
+
+pub use attractor_spec::{AttractorSpec, BuiltAttractor, WeightedSpec};
+pub use camera_spec::{CameraSpec, RotationSpec};
+pub use config::{CheckpointConfig, ProcessingConfig, RenderConfig, RenderingConfig, SimulationConfig};
+pub use error::SpecError;
+pub use generator_spec::GeneratorSpec;
+pub use integrator_spec::IntegratorSpec;
+pub use parameter::Parameter;
+pub use registry::{Arguments, Constructor, Registry};
@@ -0,0 +1,35 @@
+//! Implementation of attractor parameters which may vary between frames.
+
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+/// Value of an attractor parameter, either fixed or swept across the frames of an animation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Parameter<T> {
+    /// The same value in every frame.
+    Fixed(T),
+    /// Value moving linearly between bounds `[first, last]`, reached in the first and last frames.
+    Sweep([T; 2]),
+}
+
+impl<T: Float> Parameter<T> {
+    /// Value of the parameter in frame `index` of `total`.
+    ///
+    /// # Panics
+    ///
+    /// This function will not panic.
+    #[must_use]
+    #[inline]
+    pub fn value(&self, index: usize, total: usize) -> T {
+        debug_assert!(total > 0, "Total must be greater than 0");
+        debug_assert!(index < total, "Index out of bounds");
+        match *self {
+            Self::Fixed(value) => value,
+            Self::Sweep([first, last]) => {
+                let step = (last - first) / (T::from(total).unwrap() - T::one()).max(T::one());
+                first + step * T::from(index).unwrap()
+            }
+        }
+    }
+}
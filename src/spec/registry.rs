@@ -0,0 +1,119 @@
+//! Implementation of a registry of attractors constructed by name.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug, Formatter},
+};
+
+use crate::{BuiltAttractor, SpecError};
+
+/// Constructor of a registered attractor from its parameter values.
+pub type Constructor<T> = dyn Fn(&Arguments<T>) -> Result<BuiltAttractor<T>, SpecError> + Send + Sync;
+
+/// Parameter values passed to the constructor of a registered attractor, keyed by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arguments<T> {
+    /// Value of each named parameter.
+    values: BTreeMap<String, T>,
+}
+
+impl<T: Copy> Arguments<T> {
+    /// Creates new `Arguments` from named parameter values.
+    #[must_use]
+    #[inline]
+    pub const fn new(values: BTreeMap<String, T>) -> Self {
+        Self { values }
+    }
+
+    /// Value of the named parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no value was given for the parameter.
+    #[inline]
+    pub fn get(&self, name: &str) -> Result<T, SpecError> {
+        self.values
+            .get(name)
+            .copied()
+            .ok_or_else(|| SpecError::MissingParameter(name.to_owned()))
+    }
+
+    /// Value of the named parameter, or `default` if no value was given.
+    #[must_use]
+    #[inline]
+    pub fn get_or(&self, name: &str, default: T) -> T {
+        self.values.get(name).copied().unwrap_or(default)
+    }
+}
+
+/// Registry of attractor constructors, referred to by name from `AttractorSpec::Registered`.
+///
+/// Registering a constructor makes an attractor type defined outside this crate available to configuration files.
+/// Constructors read their parameter values from the `Arguments` and wrap the attractor in a `BuiltAttractor`.
+pub struct Registry<T> {
+    /// Constructor of each registered attractor, keyed by name.
+    constructors: BTreeMap<String, Box<Constructor<T>>>,
+}
+
+impl<T> Registry<T> {
+    /// Creates a new empty `Registry`.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            constructors: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a constructor under `name`, replacing any constructor previously registered under it.
+    #[inline]
+    pub fn register<F>(&mut self, name: &str, constructor: F) -> &mut Self
+    where
+        F: Fn(&Arguments<T>) -> Result<BuiltAttractor<T>, SpecError> + Send + Sync + 'static,
+    {
+        drop(self.constructors.insert(name.to_owned(), Box::new(constructor)));
+        self
+    }
+
+    /// Whether a constructor is registered under `name`.
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.contains_key(name)
+    }
+
+    /// Names of the registered attractors, in alphabetical order.
+    #[must_use]
+    #[inline]
+    pub fn names(&self) -> Vec<&str> {
+        self.constructors.keys().map(String::as_str).collect()
+    }
+
+    /// Constructs the attractor registered under `name` from the given parameter values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no attractor is registered under `name`, or if its constructor fails.
+    #[inline]
+    pub fn build(&self, name: &str, arguments: &Arguments<T>) -> Result<BuiltAttractor<T>, SpecError> {
+        let constructor = self
+            .constructors
+            .get(name)
+            .ok_or_else(|| SpecError::UnknownAttractor(name.to_owned()))?;
+        constructor(arguments)
+    }
+}
+
+impl<T> Default for Registry<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for Registry<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry").field("names", &self.names()).finish()
+    }
+}
@@ -0,0 +1,98 @@
+//! State types which attractors evolve.
+//!
+//! Two-dimensional attractors evolve points in the complex plane, while higher-dimensional
+//! attractors evolve `nalgebra` vectors. Initial points are always sampled in the plane and
+//! embedded into the state space before iteration.
+//!
+//! Escape-time fractals evolve an [`Orbit`], which carries the parameter `c` sampled for each orbit.
+
+use nalgebra::{Complex, SVector, Scalar};
+use num_traits::Float;
+
+/// Trait defining the interface for attractor state types.
+pub trait State<T>: Copy {
+    /// Embeds a point sampled in the complex plane into the state space.
+    fn from_plane(p: Complex<T>) -> Self;
+}
+
+impl<T> State<T> for Complex<T>
+where
+    Self: Copy,
+{
+    #[inline]
+    fn from_plane(p: Self) -> Self {
+        p
+    }
+}
+
+impl<T: Float + Scalar, const N: usize> State<T> for SVector<T, N> {
+    /// Places the real and imaginary parts in the first two components, setting all others to zero.
+    #[inline]
+    fn from_plane(p: Complex<T>) -> Self {
+        Self::from_fn(|i, _| match i {
+            0 => p.re,
+            1 => p.im,
+            _ => T::zero(),
+        })
+    }
+}
+
+/// Trait converting attractor states to and from their `N` real coordinates, so they can be analysed as vectors.
+pub trait Coordinates<T, const N: usize>: Copy {
+    /// Coordinates of the state.
+    fn coordinates(&self) -> SVector<T, N>;
+
+    /// State with the given coordinates.
+    fn from_coordinates(coordinates: SVector<T, N>) -> Self;
+}
+
+impl<T: Scalar + Copy> Coordinates<T, 2> for Complex<T> {
+    /// Uses the real and imaginary parts as the two coordinates.
+    #[inline]
+    fn coordinates(&self) -> SVector<T, 2> {
+        SVector::<T, 2>::new(self.re, self.im)
+    }
+
+    #[inline]
+    fn from_coordinates(coordinates: SVector<T, 2>) -> Self {
+        Self::new(coordinates.x, coordinates.y)
+    }
+}
+
+impl<T: Scalar + Copy, const N: usize> Coordinates<T, N> for SVector<T, N> {
+    #[inline]
+    fn coordinates(&self) -> Self {
+        *self
+    }
+
+    #[inline]
+    fn from_coordinates(coordinates: Self) -> Self {
+        coordinates
+    }
+}
+
+/// State of an escape-time orbit `z_{n+1} = f(z_n) + c`, with its own parameter `c`.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Orbit<T> {
+    /// Current point of the orbit.
+    pub z: Complex<T>,
+    /// Parameter of the orbit.
+    pub c: Complex<T>,
+}
+
+impl<T> Orbit<T> {
+    /// Creates a new `Orbit` at `z` with parameter `c`.
+    #[inline]
+    pub const fn new(z: Complex<T>, c: Complex<T>) -> Self {
+        Self { z, c }
+    }
+}
+
+impl<T: Float> State<T> for Orbit<T> {
+    /// Uses the sampled point as the parameter `c`, starting the orbit at zero.
+    #[inline]
+    fn from_plane(p: Complex<T>) -> Self {
+        Self::new(Complex::new(T::zero(), T::zero()), p)
+    }
+}
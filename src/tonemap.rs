@@ -0,0 +1,353 @@
+//! Tone mapping of density images.
+//!
+//! A tone map is a chain of operators turning the hit counts produced by the renderers into
+//! intensities in the range [0, 1], ready to be sampled by a colour map. Each operator rescales
+//! its output so that the brightest reference value maps to one.
+//!
+//! Operators which depend on the image content (the maximum count, percentiles and histograms)
+//! are fitted to reference data, producing a [`Normalisation`]. Applying the same normalisation to
+//! every frame of an animation keeps the brightness consistent from frame to frame.
+
+use ndarray::Array2;
+use num_traits::{Float, FromPrimitive};
+use std::collections::BTreeMap;
+
+use crate::Count;
+
+/// Single stage of a tone map.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Operator<T> {
+    /// Linear scaling, `x`.
+    Linear,
+    /// Logarithmic scaling, `ln(1 + x)`, which is well defined for any count.
+    Log,
+    /// Square root scaling, `sqrt(x)`.
+    Sqrt,
+    /// Gamma correction, `x^(1 / gamma)`.
+    Gamma(T),
+    /// Inverse hyperbolic sine scaling, `asinh(x / softening)`.
+    /// Linear for values much smaller than the softening and logarithmic for values much larger.
+    Asinh(T),
+    /// Percentile clipping, linearly mapping the `low` to `high` percentiles (each in [0, 1]) of the non-zero values onto [0, 1].
+    Clip {
+        /// Percentile mapped to zero.
+        low: T,
+        /// Percentile mapped to one.
+        high: T,
+    },
+    /// Histogram equalisation of the non-zero values using the given number of bins.
+    /// Zero values are treated as background and remain zero.
+    Equalise(usize),
+}
+
+/// Chain of tone mapping operators, applied in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToneMap<T> {
+    /// Operators applied in order.
+    operators: Vec<Operator<T>>,
+}
+
+impl<T: Float + FromPrimitive> ToneMap<T> {
+    /// Construct an empty tone map, which linearly scales counts by the maximum.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { operators: Vec::new() }
+    }
+
+    /// Append an operator to the chain.
+    #[must_use]
+    #[inline]
+    pub fn then(mut self, operator: Operator<T>) -> Self {
+        self.operators.push(operator);
+        self
+    }
+
+    /// Append logarithmic scaling.
+    #[must_use]
+    #[inline]
+    pub fn log(self) -> Self {
+        self.then(Operator::Log)
+    }
+
+    /// Append square root scaling.
+    #[must_use]
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        self.then(Operator::Sqrt)
+    }
+
+    /// Append gamma correction.
+    #[must_use]
+    #[inline]
+    pub fn gamma(self, gamma: T) -> Self {
+        self.then(Operator::Gamma(gamma))
+    }
+
+    /// Append inverse hyperbolic sine scaling.
+    #[must_use]
+    #[inline]
+    pub fn asinh(self, softening: T) -> Self {
+        self.then(Operator::Asinh(softening))
+    }
+
+    /// Append percentile clipping.
+    #[must_use]
+    #[inline]
+    pub fn clip(self, low: T, high: T) -> Self {
+        self.then(Operator::Clip { low, high })
+    }
+
+    /// Append histogram equalisation.
+    #[must_use]
+    #[inline]
+    pub fn equalise(self, bins: usize) -> Self {
+        self.then(Operator::Equalise(bins))
+    }
+
+    /// Operators in the chain.
+    #[must_use]
+    #[inline]
+    pub fn operators(&self) -> &[Operator<T>] {
+        &self.operators
+    }
+
+    /// Fit the tone map to a single density image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any parameter of the operators is invalid, as described in [`ToneMap::fit_frames`].
+    #[must_use]
+    #[inline]
+    pub fn fit<C: Count>(&self, counts: &Array2<C>) -> Normalisation<T> {
+        self.fit_frames(&[counts])
+    }
+
+    /// Fit the tone map to the pooled values of several density images, such as every frame of an animation.
+    ///
+    /// The frames are pooled into a running histogram of their distinct counts, so the memory used depends on the
+    /// number of distinct counts rather than on the number of pixels of every frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any parameter of the operators is invalid:
+    /// a non-positive gamma or softening, percentiles outside [0, 1] or out of order, or zero histogram bins.
+    #[must_use]
+    #[inline]
+    pub fn fit_frames<C: Count>(&self, frames: &[&Array2<C>]) -> Normalisation<T> {
+        let mut histogram = BTreeMap::new();
+        for &count in frames.iter().flat_map(|counts| counts.iter()) {
+            *histogram.entry(count.to_u64().unwrap()).or_insert(0_usize) += 1;
+        }
+        let mut values: Vec<(T, usize)> = histogram
+            .into_iter()
+            .map(|(count, frequency)| (T::from(count).unwrap(), frequency))
+            .collect();
+
+        let operators = if self.operators.is_empty() {
+            &[Operator::Linear][..]
+        } else {
+            &self.operators
+        };
+
+        let mut stages = Vec::with_capacity(operators.len());
+        for &operator in operators {
+            let stage = Stage::fit(operator, &values);
+            for &mut (ref mut value, _) in &mut values {
+                *value = stage.apply(*value);
+            }
+            stages.push(stage);
+        }
+        Normalisation { stages }
+    }
+
+    /// Fit the tone map to a density image and apply it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any parameter of the operators is invalid, as described in [`ToneMap::fit_frames`].
+    #[must_use]
+    #[inline]
+    pub fn apply<C: Count>(&self, counts: &Array2<C>) -> Array2<T> {
+        self.fit(counts).apply(counts)
+    }
+}
+
+/// Tone map fitted to reference data, which can be applied to any density image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalisation<T> {
+    /// Fitted stages applied in order.
+    stages: Vec<Stage<T>>,
+}
+
+impl<T: Float + FromPrimitive> Normalisation<T> {
+    /// Map a density image to intensities in the range [0, 1].
+    ///
+    /// Counts brighter than any in the reference data saturate at one.
+    ///
+    /// # Panics
+    ///
+    /// This function will not panic.
+    #[must_use]
+    #[inline]
+    pub fn apply<C: Count>(&self, counts: &Array2<C>) -> Array2<T> {
+        counts.mapv(|count| {
+            self.stages
+                .iter()
+                .fold(T::from(count).unwrap(), |value, stage| stage.apply(value))
+        })
+    }
+}
+
+/// Operator with the statistics of its reference input.
+#[derive(Debug, Clone, PartialEq)]
+enum Stage<T> {
+    /// Pointwise function, normalised by its value at the reference maximum.
+    Pointwise {
+        /// Operator providing the function.
+        operator: Operator<T>,
+        /// Function value at the reference maximum.
+        max: T,
+    },
+    /// Linear map from [low, high] onto [0, 1].
+    Clip {
+        /// Value mapped to zero.
+        low: T,
+        /// Value mapped to one.
+        high: T,
+    },
+    /// Lookup of the cumulative distribution of the reference values.
+    Equalise {
+        /// Upper edge of the final histogram bin.
+        max: T,
+        /// Fraction of non-zero reference values at or below each bin.
+        cdf: Vec<T>,
+    },
+}
+
+impl<T: Float + FromPrimitive> Stage<T> {
+    /// Fit an operator to its reference input values, each paired with its number of occurrences.
+    fn fit(operator: Operator<T>, values: &[(T, usize)]) -> Self {
+        let max = values.iter().fold(T::zero(), |max, &(value, _)| max.max(value));
+        match operator {
+            Operator::Linear | Operator::Log | Operator::Sqrt | Operator::Gamma(_) | Operator::Asinh(_) => {
+                assert!(
+                    !matches!(operator, Operator::Gamma(p) | Operator::Asinh(p) if p <= T::zero()),
+                    "Gamma and softening must be positive."
+                );
+                Self::Pointwise {
+                    operator,
+                    max: Self::evaluate(operator, max),
+                }
+            }
+            Operator::Clip { low, high } => {
+                assert!(
+                    T::zero() <= low && low < high && high <= T::one(),
+                    "Percentiles must satisfy 0 <= low < high <= 1."
+                );
+                let mut non_zero: Vec<(T, usize)> = values.iter().copied().filter(|&(value, _)| value > T::zero()).collect();
+                non_zero.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                Self::Clip {
+                    low: percentile(&non_zero, low),
+                    high: percentile(&non_zero, high),
+                }
+            }
+            Operator::Equalise(bins) => {
+                assert!(bins > 0, "Histogram must have at least one bin.");
+                let mut histogram = vec![0_usize; bins];
+                let mut num_non_zero = 0_usize;
+                for &(value, frequency) in values.iter().filter(|&&(value, _)| value > T::zero()) {
+                    histogram[bin(value, max, bins)] += frequency;
+                    num_non_zero += frequency;
+                }
+                let total = T::from_usize(num_non_zero.max(1)).unwrap();
+                let mut cumulative = 0_usize;
+                let cdf = histogram
+                    .into_iter()
+                    .map(|count| {
+                        cumulative += count;
+                        T::from_usize(cumulative).unwrap() / total
+                    })
+                    .collect();
+                Self::Equalise { max, cdf }
+            }
+        }
+    }
+
+    /// Evaluate the unnormalised function of a pointwise operator.
+    fn evaluate(operator: Operator<T>, value: T) -> T {
+        match operator {
+            Operator::Log => value.ln_1p(),
+            Operator::Sqrt => value.sqrt(),
+            Operator::Gamma(gamma) => value.powf(gamma.recip()),
+            Operator::Asinh(softening) => (value / softening).asinh(),
+            Operator::Linear | Operator::Clip { .. } | Operator::Equalise(_) => value,
+        }
+    }
+
+    /// Map a value to the range [0, 1].
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "The cumulative distribution can only be borrowed through match ergonomics."
+    )]
+    fn apply(&self, value: T) -> T {
+        let mapped = match self {
+            Self::Pointwise { operator, max } => {
+                if *max > T::zero() {
+                    Self::evaluate(*operator, value) / *max
+                } else {
+                    T::zero()
+                }
+            }
+            Self::Clip { low, high } => {
+                if *high > *low {
+                    (value - *low) / (*high - *low)
+                } else if value > T::zero() {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            }
+            Self::Equalise { max, cdf } => {
+                if value > T::zero() && *max > T::zero() {
+                    cdf[bin(value, *max, cdf.len())]
+                } else {
+                    T::zero()
+                }
+            }
+        };
+        mapped.max(T::zero()).min(T::one())
+    }
+}
+
+/// Linearly interpolated percentile of sorted values repeated by their frequencies, or zero if there are none.
+fn percentile<T: Float + FromPrimitive>(sorted: &[(T, usize)], fraction: T) -> T {
+    let Some(last) = sorted.iter().map(|&(_, frequency)| frequency).sum::<usize>().checked_sub(1) else {
+        return T::zero();
+    };
+    let position = fraction * T::from_usize(last).unwrap();
+    let lower = position.floor();
+    let index = lower.to_usize().unwrap().min(last);
+    let next = (index + 1).min(last);
+    let weight = position - lower;
+    nth(sorted, index) * (T::one() - weight) + nth(sorted, next) * weight
+}
+
+/// Value at an index of sorted values repeated by their frequencies, or zero if the index is out of range.
+fn nth<T: Float>(sorted: &[(T, usize)], index: usize) -> T {
+    sorted
+        .iter()
+        .scan(0_usize, |cumulative, &(value, frequency)| {
+            *cumulative += frequency;
+            Some((value, *cumulative))
+        })
+        .find(|&(_, cumulative)| index < cumulative)
+        .map_or_else(T::zero, |(value, _)| value)
+}
+
+/// Histogram bin of a value in the range (0, max], with values beyond the maximum in the final bin.
+fn bin<T: Float + FromPrimitive>(value: T, max: T, bins: usize) -> usize {
+    let scaled = (value / max * T::from_usize(bins).unwrap()).ceil();
+    scaled.to_usize().unwrap_or(bins).clamp(1, bins) - 1
+}
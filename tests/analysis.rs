@@ -0,0 +1,107 @@
+//! Analysis tools must report results in their documented order and reject invalid settings.
+
+use attract::{
+    Affine, Attractor, Clifford, Error, FlowMap, Jacobian, Lorenz, Rk4, SearchSettings, lyapunov_spectrum,
+    lyapunov_spectrum_numerical, search,
+};
+use nalgebra::{Complex, Matrix3, Vector3};
+use rand::RngCore;
+
+/// Linear map of three-dimensional space scaling each axis by its own factor.
+struct Scaling(Vector3<f64>);
+
+impl Attractor<f64, Vector3<f64>> for Scaling {
+    fn iterate_at(&self, _n: usize, p: Vector3<f64>, _rng: &mut dyn RngCore, _step: Option<f64>) -> Vector3<f64> {
+        p.component_mul(&self.0)
+    }
+}
+
+impl Jacobian<f64, Vector3<f64>, 3> for Scaling {
+    fn jacobian(&self, _p: Vector3<f64>) -> Matrix3<f64> {
+        Matrix3::from_diagonal(&self.0)
+    }
+}
+
+#[test]
+fn lyapunov_exponents_are_sorted_from_largest_to_smallest() {
+    // The first basis vector contracts fastest, so the QR diagonal is in ascending order
+    let affine = Affine::new(0.5, 0.0, 0.0, 0.0, 0.9, 0.0);
+    let spectrum = lyapunov_spectrum(&affine, Complex::new(0.1, 0.1), 0, 100);
+
+    assert!((spectrum.exponents[0] - 0.9_f64.ln()).abs() < 1e-12);
+    assert!((spectrum.exponents[1] - 0.5_f64.ln()).abs() < 1e-12);
+    assert!(spectrum.history.iter().all(|&[first, second]| first >= second));
+    assert_eq!(spectrum.largest(), spectrum.exponents[0]);
+}
+
+#[test]
+fn spatial_spectra_have_one_exponent_per_dimension() {
+    let scaling = Scaling(Vector3::new(0.5, 2.0, 0.9));
+    let spectrum = lyapunov_spectrum(&scaling, Vector3::new(0.1, 0.1, 0.1), 0, 10);
+    let expected = [2.0_f64.ln(), 0.9_f64.ln(), 0.5_f64.ln()];
+    for (exponent, expected) in spectrum.exponents.iter().zip(expected) {
+        assert!((exponent - expected).abs() < 1e-12);
+    }
+
+    let numerical = lyapunov_spectrum_numerical(&scaling, Vector3::new(0.1, 0.1, 0.1), 0, 10, 1e-6);
+    for (exponent, expected) in numerical.exponents.iter().zip(expected) {
+        assert!((exponent - expected).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn lorenz_spectrum_matches_published_values() {
+    let step_size: f64 = 0.01;
+    let lorenz = FlowMap::new(Lorenz::new(10.0, 28.0, 8.0 / 3.0), Rk4::new(), step_size);
+    let spectrum = lyapunov_spectrum_numerical(&lorenz, Vector3::new(1.0, 1.0, 1.0), 1_000, 20_000, 1e-7);
+    let [largest, middle, smallest] = spectrum.exponents.map(|exponent| exponent / step_size);
+
+    // The exponents sum to the constant divergence of the flow, -(sigma + 1 + beta)
+    assert!((largest + middle + smallest + 41.0 / 3.0).abs() < 0.05);
+    assert!((largest - 0.906).abs() < 0.2, "largest exponent {largest}");
+    assert!(middle.abs() < 0.1, "middle exponent {middle}");
+    assert!((smallest + 14.572).abs() < 0.25, "smallest exponent {smallest}");
+}
+
+/// Small search settings.
+fn search_settings(grid_resolution: usize) -> SearchSettings<f64> {
+    SearchSettings {
+        num_trials: 1,
+        start: [0.05, 0.05],
+        warmup: 10,
+        iterations: 100,
+        escape_radius: 1.0e6,
+        max_period: 8,
+        min_lyapunov: 0.01,
+        grid_resolution,
+        min_coverage: 0.1,
+    }
+}
+
+/// Searches Clifford attractors within the given ranges.
+fn search_clifford(ranges: &[[f64; 2]], settings: &SearchSettings<f64>) -> Result<usize, Error> {
+    search(
+        |p| Ok(Clifford::new(p[0], p[1], p[2], p[3])),
+        ranges,
+        settings,
+        &mut rand::rng(),
+    )
+    .map(|candidates| candidates.len())
+}
+
+#[test]
+fn zero_grid_resolution_is_rejected() {
+    let ranges = [[-3.0, 3.0], [-3.0, 3.0], [-2.0, 2.0], [-2.0, 2.0]];
+    assert_eq!(search_clifford(&ranges, &search_settings(0)), Err(Error::ZeroGridResolution));
+}
+
+#[test]
+fn invalid_ranges_are_rejected() {
+    let settings = search_settings(64);
+    let inverted = [[-3.0, 3.0], [3.0, -3.0], [-2.0, 2.0], [-2.0, 2.0]];
+    assert_eq!(search_clifford(&inverted, &settings), Err(Error::InvalidRange([3.0, -3.0])));
+    let infinite = [[-3.0, 3.0], [-3.0, 3.0], [f64::NEG_INFINITY, 2.0], [-2.0, 2.0]];
+    assert!(matches!(search_clifford(&infinite, &settings), Err(Error::InvalidRange(_))));
+    let degenerate = [[-1.4, -1.4], [1.6, 1.6], [1.0, 1.0], [0.7, 0.7]];
+    assert!(search_clifford(&degenerate, &settings).is_ok());
+}
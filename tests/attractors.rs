@@ -0,0 +1,72 @@
+//! Built-in attractors must stay finite at the edges of their recommended parameter ranges.
+
+use std::f64::consts::TAU;
+
+use attract::{Attractor, Jacobian, Parametric, SymmetricIcon, Zaslavsky};
+use nalgebra::{Complex, Matrix2};
+
+/// Approximates the Jacobian of a planar map by central differences.
+fn finite_difference_jacobian<A: Attractor<f64>>(attractor: &A, p: Complex<f64>) -> Matrix2<f64> {
+    let h = 1e-6;
+    let dx = (attractor.iterate(p + Complex::new(h, 0.0)) - attractor.iterate(p - Complex::new(h, 0.0))) / (2.0 * h);
+    let dy = (attractor.iterate(p + Complex::new(0.0, h)) - attractor.iterate(p - Complex::new(0.0, h))) / (2.0 * h);
+    Matrix2::new(dx.re, dy.re, dx.im, dy.im)
+}
+
+/// Symmetric icon of the given degree with generic parameters.
+fn icon(degree: i32) -> SymmetricIcon<f64> {
+    SymmetricIcon::new(-2.08, 1.0, -0.1, 0.167, 0.1, degree)
+}
+
+#[test]
+fn undamped_zaslavsky_is_finite() {
+    let zaslavsky = Zaslavsky::<f64>::new(7.0, 0.2, 0.0);
+    assert_eq!(zaslavsky.parameter_ranges()[2][0], 0.0);
+
+    // Without damping mu is one, so the map reduces to an undamped kicked rotator
+    let p = zaslavsky.iterate(Complex::new(0.25, 0.5));
+    assert!((p.re - 0.55).abs() < 1e-12);
+    assert!((p.im - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn symmetric_icon_jacobian_matches_finite_differences() {
+    let p = Complex::new(0.3, -0.7);
+    for degree in 1..=6 {
+        let difference = icon(degree).jacobian(p) - finite_difference_jacobian(&icon(degree), p);
+        assert!(difference.amax() < 1e-6, "degree {degree}: {difference}");
+    }
+}
+
+#[test]
+fn symmetric_icon_commutes_with_its_rotations() {
+    let p = Complex::new(0.4, 0.2);
+    for degree in 1..=6 {
+        let rotation = Complex::from_polar(1.0, TAU / f64::from(degree));
+        let difference = icon(degree).iterate(rotation * p) - rotation * icon(degree).iterate(p);
+        assert!(difference.norm() < 1e-12, "degree {degree}: {difference}");
+    }
+}
+
+#[test]
+fn symmetric_icon_without_omega_commutes_with_reflection() {
+    let icon = SymmetricIcon::new(-2.08, 1.0, -0.1, 0.167, 0.0, 5);
+    let p = Complex::new(0.4, 0.2);
+    assert!((icon.iterate(p.conj()) - icon.iterate(p).conj()).norm() < 1e-12);
+}
+
+#[test]
+fn symmetric_icon_orbit_stays_bounded() {
+    let icon = SymmetricIcon::new(-2.08, 1.0, -0.1, 0.167, 0.0, 7);
+    let mut p = Complex::new(0.01, 0.003);
+    for _ in 0..10_000 {
+        p = icon.iterate(p);
+        assert!(p.norm() < 10.0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Degree of symmetry must be at least one")]
+fn symmetric_icon_without_symmetry_panics() {
+    let _ = icon(0);
+}
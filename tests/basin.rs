@@ -0,0 +1,85 @@
+//! Basins must classify orbits through the iteration index and random streams of `render_basin`, and report cancellation.
+
+use attract::{Affine, Alternate, BasinSettings, Cancellation, Error, Henon, Outcome, Perturbed, Silent, render_basin};
+
+/// Translation of the real coordinate.
+fn shift(c: f64) -> Affine<f64> {
+    Affine::new(1.0, 0.0, c, 0.0, 1.0, 0.0)
+}
+
+#[test]
+fn contraction_converges_to_its_fixed_point() {
+    let settings = BasinSettings::builder(Affine::new(0.5, 0.0, 0.0, 0.0, 0.5, 0.0))
+        .resolution([8, 8])
+        .progress(Silent::new())
+        .build()
+        .unwrap();
+    let basin = render_basin(&settings);
+    assert!(basin.is_complete());
+    assert!(basin.labels.iter().all(|&label| label == Outcome::FixedPoint(0)));
+    assert_eq!(basin.fixed_points.len(), 1);
+    assert!(basin.fixed_points[0].norm() < 1.0e-6);
+}
+
+#[test]
+fn alternation_is_classified_through_the_iteration_index() {
+    // Iterating without the index would only ever apply the first translation, and escape.
+    let settings = BasinSettings::builder(Alternate::new(shift(1.0), shift(-1.0)))
+        .resolution([8, 8])
+        .progress(Silent::new())
+        .build()
+        .unwrap();
+    let basin = render_basin(&settings);
+    assert!(basin.labels.iter().all(|&label| label == Outcome::Periodic(2)));
+}
+
+#[test]
+fn seeded_stochastic_basins_are_reproducible() {
+    let basin = |seed| {
+        let settings = BasinSettings::builder(Perturbed::new(Henon::new(1.4, 0.3), 0.01))
+            .resolution([16, 16])
+            .scale(3.0)
+            .seed(seed)
+            .progress(Silent::new())
+            .build()
+            .unwrap();
+        render_basin(&settings)
+    };
+    let first = basin(7);
+    let second = basin(7);
+    assert_eq!(first.labels, second.labels);
+    assert_eq!(first.escape_times, second.escape_times);
+}
+
+#[test]
+fn cancelled_rows_are_unclassified() {
+    let cancellation = Cancellation::new();
+    cancellation.cancel();
+    let settings = BasinSettings::builder(Henon::new(1.4, 0.3))
+        .resolution([4, 4])
+        .progress(Silent::new())
+        .cancellation(cancellation)
+        .build()
+        .unwrap();
+    let basin = render_basin(&settings);
+    assert!(!basin.is_complete());
+    assert!(basin.labels.iter().all(|&label| label == Outcome::Unclassified));
+    assert!(basin.escape_times.iter().all(|&time| time == 0));
+}
+
+#[test]
+fn invalid_settings_are_rejected() {
+    let builder = || BasinSettings::builder(Henon::new(1.4, 0.3)).progress(Silent::new());
+    assert!(matches!(builder().resolution([0, 4]).build(), Err(Error::ZeroResolution(_))));
+    assert!(matches!(builder().scale(0.0).build(), Err(Error::InvalidScale(_))));
+    assert!(matches!(
+        builder().offset([f64::NAN, 0.0]).build(),
+        Err(Error::InvalidOffset(_))
+    ));
+    assert!(matches!(builder().step_size(-1.0).build(), Err(Error::InvalidStepSize(_))));
+    assert!(matches!(
+        builder().escape_radius(f64::INFINITY).build(),
+        Err(Error::InvalidEscapeRadius(_))
+    ));
+    assert!(matches!(builder().tolerance(0.0).build(), Err(Error::InvalidTolerance(_))));
+}
@@ -0,0 +1,73 @@
+//! Bifurcation diagrams must plot each parameter value in its own column, and reject ranges they cannot map.
+
+use attract::{Affine, Error, Settings, Silent, Sweep, render_bifurcation};
+use nalgebra::Complex;
+use ndarray::Array2;
+use std::sync::Arc;
+
+/// Sweep of constant maps sending every point to the swept value on the real axis.
+fn constant_sweep(parameter_range: [f64; 2], value_range: [f64; 2]) -> Sweep<f64> {
+    Sweep {
+        family: Box::new(|a| Box::new(Affine::new(0.0, 0.0, a, 0.0, 0.0, 0.0))),
+        observable: Arc::new(|p: &Complex<f64>| p.re),
+        parameter_range,
+        value_range,
+    }
+}
+
+/// Small seeded settings.
+fn settings() -> Settings<f64> {
+    Settings::builder(Affine::identity())
+        .resolution([16, 8])
+        .num_samples(4)
+        .num_groups(2)
+        .seed(7)
+        .max_iter(10)
+        .warmup(0)
+        .progress(Silent::new())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn each_column_plots_its_parameter_value() {
+    let diagram: Array2<u32> = render_bifurcation(&settings(), &constant_sweep([-0.5, 0.5], [-1.0, 1.0])).unwrap();
+    let rows: Vec<usize> = diagram
+        .columns()
+        .into_iter()
+        .map(|column| {
+            let visited: Vec<usize> = column
+                .indexed_iter()
+                .filter(|&(_, &count)| count > 0)
+                .map(|(row, _)| row)
+                .collect();
+            assert_eq!(visited.len(), 1, "every sample of a column lands on one value");
+            assert_eq!(column[visited[0]], 40);
+            visited[0]
+        })
+        .collect();
+
+    // Larger values are plotted nearer the top row
+    assert!(rows.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(rows[0] > rows[7]);
+}
+
+#[test]
+fn wide_counts_match_narrow_counts() {
+    let sweep = constant_sweep([-0.5, 0.5], [-1.0, 1.0]);
+    let narrow: Array2<u32> = render_bifurcation(&settings(), &sweep).unwrap();
+    let wide: Array2<u64> = render_bifurcation(&settings(), &sweep).unwrap();
+    assert_eq!(wide, narrow.mapv(u64::from));
+}
+
+#[test]
+fn invalid_ranges_are_rejected() {
+    let render = |parameter_range, value_range| {
+        render_bifurcation::<u32, _, _>(&settings(), &constant_sweep(parameter_range, value_range)).err()
+    };
+    assert_eq!(render([-0.5, 0.5], [1.0, -1.0]), Some(Error::InvalidRange([1.0, -1.0])));
+    assert_eq!(render([-0.5, 0.5], [1.0, 1.0]), Some(Error::InvalidRange([1.0, 1.0])));
+    assert_eq!(render([0.5, -0.5], [-1.0, 1.0]), Some(Error::InvalidRange([0.5, -0.5])));
+    assert!(matches!(render([f64::NAN, 0.5], [-1.0, 1.0]), Some(Error::InvalidRange(_))));
+    assert!(render([0.5, 0.5], [-1.0, 1.0]).is_none());
+}
@@ -0,0 +1,94 @@
+//! Channel means must average the orbit visits to each pixel.
+
+use std::f64::consts::PI;
+
+use attract::{Attractor, Channel, Layers, Projection, Settings, Silent, render_channels};
+use nalgebra::Complex;
+use rand::RngCore;
+
+/// Map cycling through a fixed sequence of points, entering the cycle at its first point.
+struct Cycle(Vec<Complex<f64>>);
+
+impl Attractor<f64> for Cycle {
+    fn iterate_at(&self, _n: usize, p: Complex<f64>, _rng: &mut dyn RngCore, _step: Option<f64>) -> Complex<f64> {
+        let next = self.0.iter().position(|&q| q == p).map_or(0, |i| (i + 1) % self.0.len());
+        self.0[next]
+    }
+}
+
+/// Identity projection hiding a single point.
+struct Hiding(Complex<f64>);
+
+impl Projection<f64, Complex<f64>> for Hiding {
+    fn project(&self, p: &Complex<f64>) -> Option<Complex<f64>> {
+        (*p != self.0).then_some(*p)
+    }
+}
+
+/// Builds small seeded settings with 100 iterations after an even warmup.
+fn settings(cycle: Cycle, resolution: usize) -> Settings<f64> {
+    Settings::builder(cycle)
+        .resolution([resolution, resolution])
+        .scale(4.0)
+        .num_samples(16)
+        .num_groups(4)
+        .seed(42)
+        .max_iter(100)
+        .warmup(2)
+        .progress(Silent::new())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn means_average_the_visits_to_each_pixel() {
+    // The left point lands in pixel [1, 1] at even iterations, and the right point in pixel [1, 0] at odd iterations.
+    let cycle = Cycle(vec![Complex::new(-1.0, 0.0), Complex::new(1.0, 0.0)]);
+    let channels = [
+        Channel::StepLength,
+        Channel::Heading,
+        Channel::Iteration,
+        Channel::Scalar(Box::new(|p: &Complex<f64>| p.re)),
+    ];
+    let layers: Layers<f64> = render_channels(&settings(cycle, 3), &channels);
+    assert_eq!(layers.num_channels(), 4);
+
+    let (left, right, unvisited) = ([1, 1], [1, 0], [0, 0]);
+    assert_eq!(layers.counts()[left], 16 * 50);
+    assert_eq!(layers.counts()[right], 16 * 50);
+
+    let step_length = layers.mean(0);
+    assert_eq!((step_length[left], step_length[right]), (2.0, 2.0));
+    let heading = layers.mean(1);
+    assert_eq!((heading[left], heading[right]), (PI, 0.0));
+    let iteration = layers.mean(2);
+    assert_eq!((iteration[left], iteration[right]), (49.0, 50.0));
+    let scalar = layers.mean(3);
+    assert_eq!((scalar[left], scalar[right]), (-1.0, 1.0));
+
+    for index in 0..4 {
+        assert_eq!(layers.mean(index)[unvisited], 0.0);
+    }
+}
+
+#[test]
+fn visits_without_a_visible_previous_state_have_no_step() {
+    // Every visible point lands in pixel [0, 0], and the first is reached from the hidden origin.
+    let (hidden, first, second) = (Complex::new(0.0, 0.0), Complex::new(0.5, 0.0), Complex::new(1.0, 0.0));
+    let settings = Settings::builder(Cycle(vec![hidden, first, second]))
+        .resolution([2, 2])
+        .scale(4.0)
+        .num_samples(4)
+        .num_groups(1)
+        .seed(42)
+        .max_iter(30)
+        .progress(Silent::new())
+        .projection(Hiding(hidden))
+        .build()
+        .unwrap();
+    let layers: Layers<f64> = render_channels(&settings, &[Channel::StepLength, Channel::Heading]);
+
+    assert_eq!(layers.counts()[[0, 0]], 4 * 20);
+    assert_eq!(layers.mean(0)[[0, 0]], 0.5);
+    assert_eq!(layers.mean(1)[[0, 0]], 0.0);
+}
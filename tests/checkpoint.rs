@@ -0,0 +1,149 @@
+//! Checkpoints must round-trip through their file format, and reject files they cannot represent.
+
+use attract::{Checkpoint, Clifford, Settings, Silent, render_resumable};
+use std::{fs::write, io::ErrorKind, path::PathBuf};
+
+/// Builds small seeded settings.
+fn settings() -> Settings<f64> {
+    Settings::builder(Clifford::new(-1.4, 1.6, 1.0, 0.7))
+        .resolution([32, 48])
+        .num_samples(64)
+        .num_groups(4)
+        .seed(7)
+        .max_iter(100)
+        .warmup(10)
+        .progress(Silent::new())
+        .build()
+        .unwrap()
+}
+
+/// Path of a scratch file for the named test.
+fn scratch(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("{name}.bin"))
+}
+
+#[test]
+fn narrow_counts_load_as_wide_counts() {
+    let settings = settings();
+    let narrow: Checkpoint<u32> = render_resumable(&settings, Checkpoint::new(&settings), None, 64).unwrap();
+    let path = scratch("narrow_counts_load_as_wide_counts");
+    narrow.save(&path).unwrap();
+
+    let wide: Checkpoint<u64> = Checkpoint::load(&path).unwrap();
+    assert_eq!(wide.num_samples, narrow.num_samples);
+    assert_eq!(wide.counts, narrow.counts.mapv(u64::from));
+}
+
+#[test]
+fn wide_counts_do_not_load_as_narrow_counts() {
+    let settings = settings();
+    let wide: Checkpoint<u64> = render_resumable(&settings, Checkpoint::new(&settings), None, 64).unwrap();
+    let path = scratch("wide_counts_do_not_load_as_narrow_counts");
+    wide.save(&path).unwrap();
+
+    assert!(Checkpoint::<u32>::load(&path).is_err());
+    assert_eq!(wide.saturated_pixels(), 0);
+}
+
+#[test]
+fn save_and_load_round_trip() {
+    let settings = settings();
+    let checkpoint: Checkpoint = render_resumable(&settings, Checkpoint::new(&settings), None, 64).unwrap();
+    let path = scratch("save_and_load_round_trip");
+    checkpoint.save(&path).unwrap();
+
+    assert_eq!(Checkpoint::load(&path).unwrap(), checkpoint);
+}
+
+#[test]
+fn save_to_temporary_extension() {
+    let settings = settings();
+    let checkpoint: Checkpoint = render_resumable(&settings, Checkpoint::new(&settings), None, 64).unwrap();
+    let path = scratch("save_to_temporary_extension").with_extension("tmp");
+    checkpoint.save(&path).unwrap();
+
+    assert_eq!(Checkpoint::load(&path).unwrap(), checkpoint);
+}
+
+#[test]
+fn bad_magic_is_rejected() {
+    let path = scratch("bad_magic_is_rejected");
+    write(&path, b"NOTATTRACTOR CHECKPOINT").unwrap();
+
+    let error = Checkpoint::<u32>::load(&path).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn hash_mismatch_is_rejected() {
+    let settings = settings();
+    let checkpoint: Checkpoint = Checkpoint::new(&settings);
+    let other = Settings::builder(Clifford::new(-1.4, 1.6, 1.0, 0.7))
+        .resolution([32, 48])
+        .scale(2.0)
+        .num_samples(64)
+        .num_groups(4)
+        .progress(Silent::new())
+        .build()
+        .unwrap();
+
+    let error = render_resumable(&other, checkpoint.clone(), None, 64).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(checkpoint.clone().merge(&Checkpoint::new(&other)).is_err());
+}
+
+#[test]
+fn batches_never_overshoot_the_target() {
+    let settings = settings();
+    let checkpoint: Checkpoint = render_resumable(&settings, Checkpoint::new(&settings), None, 5).unwrap();
+    assert_eq!(checkpoint.num_samples, 64);
+
+    // Every sample plots all of its iterations, so uneven batches plot as many points as a single batch
+    let single: Checkpoint = render_resumable(&settings, Checkpoint::new(&settings), None, 64).unwrap();
+    assert_eq!(checkpoint.counts.sum(), single.counts.sum());
+}
+
+#[test]
+fn zero_batch_size_is_rejected() {
+    let settings = settings();
+    let error = render_resumable::<u32, _, _>(&settings, Checkpoint::new(&settings), None, 0).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+}
+
+/// Header of a checkpoint of `u32` counts with the given image size.
+fn header(height: u64, width: u64) -> Vec<u8> {
+    let mut bytes = b"ATTRACT\0".to_vec();
+    bytes.extend(2_u32.to_le_bytes());
+    bytes.extend(4_u32.to_le_bytes());
+    bytes.extend(0_u64.to_le_bytes());
+    bytes.extend(height.to_le_bytes());
+    bytes.extend(width.to_le_bytes());
+    bytes.extend(0_u64.to_le_bytes());
+    bytes
+}
+
+#[test]
+fn oversized_header_is_rejected_before_allocating() {
+    let path = scratch("oversized_header_is_rejected_before_allocating");
+    write(&path, header(1 << 40, 1 << 40)).unwrap();
+    assert_eq!(Checkpoint::<u32>::load(&path).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    write(&path, header(u64::MAX, 2)).unwrap();
+    assert_eq!(Checkpoint::<u32>::load(&path).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn truncated_and_padded_counts_are_rejected() {
+    let path = scratch("truncated_and_padded_counts_are_rejected");
+    let mut bytes = header(2, 3);
+    bytes.extend([0; 4 * 6]);
+    write(&path, &bytes).unwrap();
+    assert!(Checkpoint::<u32>::load(&path).is_ok());
+
+    write(&path, &bytes[..bytes.len() - 1]).unwrap();
+    assert_eq!(Checkpoint::<u32>::load(&path).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    bytes.push(0);
+    write(&path, &bytes).unwrap();
+    assert_eq!(Checkpoint::<u32>::load(&path).unwrap_err().kind(), ErrorKind::InvalidData);
+}
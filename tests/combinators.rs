@@ -0,0 +1,84 @@
+//! Combinators must apply the attractors they wrap in the documented order, forwarding the iteration index.
+
+use attract::{Affine, Alternate, Attractor, Compose, Jacobian, Modulated, Perturbed, RandomSwitch, Schedule};
+use nalgebra::{Complex, Matrix2};
+use rand::{SeedableRng as _, rngs::StdRng};
+
+/// Translation of the real coordinate.
+fn shift(c: f64) -> Affine<f64> {
+    Affine::new(1.0, 0.0, c, 0.0, 1.0, 0.0)
+}
+
+/// Scaling of the real coordinate.
+fn scale(a: f64) -> Affine<f64> {
+    Affine::new(a, 0.0, 0.0, 0.0, 1.0, 0.0)
+}
+
+/// Iterates an attractor at iteration `n` from a point on the real axis, returning the real coordinate.
+fn at<A: Attractor<f64>>(attractor: &A, n: usize, x: f64) -> f64 {
+    attractor.iterate_at(n, Complex::new(x, 0.0), &mut rand::rng(), None).re
+}
+
+#[test]
+fn compose_applies_inner_then_outer() {
+    let compose = Compose::new(shift(1.0), scale(2.0));
+    assert_eq!(at(&compose, 0, 1.0), 3.0);
+    assert_eq!(compose.iterate(Complex::new(1.0, 0.0)).re, 3.0);
+
+    let reversed = Compose::new(scale(2.0), shift(1.0));
+    assert_eq!(at(&reversed, 0, 1.0), 4.0);
+}
+
+#[test]
+fn compose_jacobian_follows_the_chain_rule() {
+    let compose = Compose::new(scale(3.0), scale(2.0));
+    assert_eq!(compose.jacobian(Complex::new(1.0, 0.0)), Matrix2::new(6.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn alternate_applies_first_at_even_and_second_at_odd_iterations() {
+    let alternate = Alternate::new(shift(1.0), shift(10.0));
+    let applied: Vec<f64> = (0..6).map(|n| at(&alternate, n, 0.0)).collect();
+    assert_eq!(applied, [1.0, 10.0, 1.0, 10.0, 1.0, 10.0]);
+    assert_eq!(alternate.iterate(Complex::new(0.0, 0.0)).re, 1.0);
+}
+
+#[test]
+fn nested_alternations_take_turns() {
+    let alternate = Alternate::new(Alternate::new(shift(1.0), shift(100.0)), shift(10.0));
+    let applied: Vec<f64> = (0..8).map(|n| at(&alternate, n, 0.0)).collect();
+    assert_eq!(applied, [1.0, 10.0, 100.0, 10.0, 1.0, 10.0, 100.0, 10.0]);
+}
+
+#[test]
+fn alternate_counts_the_applications_of_each_attractor() {
+    let counter = Modulated::new(Schedule::Custom(Box::new(|n| n as f64)), shift);
+    let alternate = Alternate::new(counter, shift(0.0));
+    assert_eq!(at(&alternate, 6, 0.0), 3.0);
+    assert_eq!(at(&alternate, 7, 0.0), 0.0);
+}
+
+#[test]
+fn compose_forwards_the_iteration_index() {
+    let counter = || Modulated::new(Schedule::Custom(Box::new(|n| n as f64)), shift);
+    let compose = Compose::new(counter(), counter());
+    assert_eq!(at(&compose, 5, 0.0), 10.0);
+}
+
+#[test]
+fn random_switch_and_perturbed_draw_from_the_given_stream() {
+    let switch = RandomSwitch::new(vec![(shift(1.0), 1.0), (shift(-1.0), 1.0)]);
+    let perturbed = Perturbed::new(switch, 0.1);
+    let orbit = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut p = Complex::new(0.0, 0.0);
+        (0..50)
+            .map(|n| {
+                p = perturbed.iterate_at(n, p, &mut rng, None);
+                p
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(orbit(3), orbit(3));
+    assert_ne!(orbit(3), orbit(4));
+}
@@ -0,0 +1,95 @@
+//! Escape-time maps must iterate their orbits, and escape conditions must split the orbits they plot.
+
+use attract::{Attractor, Escape, Generator, Julia, Mandelbrot, Multibrot, Orbit, Settings, Silent, render};
+use nalgebra::Complex;
+
+/// Builds small seeded settings for the Mandelbrot map, viewing a square inside the escape disc.
+fn settings(escape: Option<Escape<f64>>) -> Settings<f64, Orbit<f64>> {
+    let generator = Generator::Aabb {
+        centre: Complex::new(0.0, 0.0),
+        half_size: Complex::new(1.4, 1.4),
+    };
+    let builder = Settings::builder(Mandelbrot::new())
+        .generator(generator)
+        .resolution([32, 32])
+        .scale(2.8)
+        .num_samples(2000)
+        .num_groups(4)
+        .seed(7)
+        .max_iter(50)
+        .warmup(0)
+        .progress(Silent::new());
+    match escape {
+        Some(condition) => builder.escape(condition),
+        None => builder,
+    }
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn mandelbrot_orbits_start_at_zero_with_their_parameter() {
+    let c = Complex::new(-0.5, 0.25);
+    let first = Mandelbrot::new().iterate(Orbit::new(Complex::new(0.0, 0.0), c));
+    assert_eq!((first.z, first.c), (c, c));
+    let second = Mandelbrot::new().iterate(first);
+    assert_eq!((second.z, second.c), (c * c + c, c));
+}
+
+#[test]
+fn multibrot_raises_to_its_degree() {
+    let orbit = Orbit::new(Complex::new(0.5, -0.5), Complex::new(0.1, 0.2));
+    let squared = Multibrot::new(2).iterate(orbit);
+    assert_eq!(squared.z, Mandelbrot::new().iterate(orbit).z);
+    let cubed = Multibrot::new(3).iterate(orbit);
+    assert_eq!(cubed.z, orbit.z * orbit.z * orbit.z + orbit.c);
+    assert_eq!(cubed.c, orbit.c);
+}
+
+#[test]
+#[should_panic(expected = "Degree must be at least two")]
+fn multibrot_of_degree_one_panics() {
+    let _ = Multibrot::new(1);
+}
+
+#[test]
+fn julia_fixes_its_parameter() {
+    let c = Complex::new(-0.8, 0.156);
+    let julia = Julia::new(c);
+    let z = Complex::new(0.3, 0.4);
+    assert_eq!(julia.iterate(z), z * z + c);
+    assert_eq!(julia.iterate(Complex::new(0.0, 0.0)), c);
+}
+
+#[test]
+fn escaping_and_bounded_orbits_partition_the_render() {
+    let escaping = render(&settings(Some(Escape::Escaping(2.0))));
+    let bounded = render(&settings(Some(Escape::Bounded(2.0))));
+    let all = render(&settings(None));
+
+    assert!(escaping.iter().any(|&count| count > 0));
+    assert!(bounded.iter().any(|&count| count > 0));
+    assert_eq!(escaping + bounded, all);
+}
+
+#[test]
+fn orbits_escaping_immediately_are_not_plotted() {
+    // Every parameter lies outside the disc, so every orbit escapes at its first iteration.
+    let generator = Generator::Aabb {
+        centre: Complex::new(3.0, 0.0),
+        half_size: Complex::new(0.5, 0.5),
+    };
+    let settings = Settings::builder(Mandelbrot::new())
+        .generator(generator)
+        .resolution([16, 16])
+        .scale(8.0)
+        .num_samples(100)
+        .num_groups(1)
+        .seed(7)
+        .warmup(0)
+        .escape(Escape::Escaping(2.0))
+        .progress(Silent::new())
+        .build()
+        .unwrap();
+    assert!(render(&settings).iter().all(|&count| count == 0));
+}
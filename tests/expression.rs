@@ -0,0 +1,55 @@
+//! Formulae must parse with the documented precedence and associativity.
+
+use attract::{Attractor, Expression};
+use nalgebra::Complex;
+
+/// Evaluates a formula of `x` and `y` at a point.
+fn evaluate(formula: &str, x: f64, y: f64) -> f64 {
+    let expression = Expression::<f64>::planar(formula, "0", &[]).unwrap();
+    expression.iterate(Complex::new(x, y)).re
+}
+
+#[test]
+fn exponentiation_binds_more_tightly_than_negation() {
+    assert_eq!(evaluate("-x^2", 3.0, 0.0), -9.0);
+    assert_eq!(evaluate("(-x)^2", 3.0, 0.0), 9.0);
+    assert_eq!(evaluate("2^-1", 0.0, 0.0), 0.5);
+}
+
+#[test]
+fn exponentiation_is_right_associative() {
+    assert_eq!(evaluate("2^3^2", 0.0, 0.0), 512.0);
+    assert_eq!(evaluate("(2^3)^2", 0.0, 0.0), 64.0);
+}
+
+#[test]
+fn products_bind_more_tightly_than_sums() {
+    assert_eq!(evaluate("x + y * 4", 2.0, 3.0), 14.0);
+    assert_eq!(evaluate("(x + y) * 4", 2.0, 3.0), 20.0);
+    assert_eq!(evaluate("x - y - 1", 2.0, 3.0), -2.0);
+    assert_eq!(evaluate("8 / x / 2", 2.0, 3.0), 2.0);
+}
+
+#[test]
+fn malformed_formulae_are_rejected() {
+    for formula in ["x +", "(x", "x)", "2 ^", "sin(x", "unknown(x)", "z"] {
+        assert!(
+            Expression::<f64>::planar(formula, "0", &[]).is_err(),
+            "{formula} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn deeply_nested_formulae_are_rejected() {
+    let parentheses = format!("{}x{}", "(".repeat(100_000), ")".repeat(100_000));
+    let signs = format!("{}x", "-".repeat(100_000));
+    let exponents = format!("x{}", "^x".repeat(100_000));
+    for formula in [parentheses, signs, exponents] {
+        let error = Expression::<f64>::planar(&formula, "0", &[]).err().unwrap();
+        assert!(error.to_string().starts_with("Formula is nested too deeply"), "{error}");
+    }
+
+    assert_eq!(evaluate(&format!("{}x{}", "(".repeat(200), ")".repeat(200)), 2.0, 0.0), 2.0);
+    assert_eq!(evaluate(&format!("{}x", "-".repeat(200)), 2.0, 0.0), 2.0);
+}
@@ -0,0 +1,147 @@
+//! Integrators must converge at their documented order, and flow maps must follow their flows through time.
+
+use attract::{
+    Aizawa, Attractor, Chen, DormandPrince, Flow, FlowMap, Halvorsen, Integrator, Leapfrog, Lorenz, Rk4, Rossler, Thomas,
+};
+use nalgebra::{SVector, Vector1, Vector2, Vector3};
+
+/// Exponential decay `dx/dt = -x`, with solution `x(t) = x(0) * exp(-t)`.
+struct Decay;
+
+impl Flow<f64, 1> for Decay {
+    fn derivative(&self, _t: f64, state: &Vector1<f64>) -> Vector1<f64> {
+        -state
+    }
+}
+
+/// Harmonic oscillator `dq/dt = p`, `dp/dt = -q`, conserving the energy `(q^2 + p^2) / 2`.
+struct Oscillator;
+
+impl Flow<f64, 2> for Oscillator {
+    fn derivative(&self, _t: f64, state: &Vector2<f64>) -> Vector2<f64> {
+        Vector2::new(state.y, -state.x)
+    }
+}
+
+/// Non-autonomous flow `dx/dt = t`, with solution `x(t) = x(0) + t^2 / 2`.
+struct Clock;
+
+impl Flow<f64, 1> for Clock {
+    fn derivative(&self, t: f64, _state: &Vector1<f64>) -> Vector1<f64> {
+        Vector1::new(t)
+    }
+}
+
+/// Error of integrating exponential decay from one to `t = 1` in `steps` steps.
+fn decay_error<I: Integrator<f64>>(integrator: &I, steps: u32) -> f64 {
+    let dt = 1.0 / f64::from(steps);
+    let state = (0..steps).fold(Vector1::new(1.0), |state, i| {
+        integrator.step(&Decay, f64::from(i) * dt, state, dt)
+    });
+    (state.x - (-1.0_f64).exp()).abs()
+}
+
+#[test]
+fn rk4_converges_at_fourth_order() {
+    let order = (decay_error(&Rk4::new(), 10) / decay_error(&Rk4::new(), 20)).log2();
+    assert!((order - 4.0).abs() < 0.1, "observed order {order}");
+}
+
+#[test]
+fn dormand_prince_meets_its_tolerance() {
+    let coarse = decay_error(&DormandPrince::new(1.0e-6), 1);
+    let fine = decay_error(&DormandPrince::new(1.0e-10), 1);
+    assert!(coarse < 1.0e-5, "error {coarse}");
+    assert!(fine < 1.0e-9, "error {fine}");
+    assert!(fine < coarse);
+
+    // A full period of the oscillator returns to its initial state
+    let period = DormandPrince::new(1.0e-10).step(&Oscillator, 0.0, Vector2::new(1.0, 0.0), std::f64::consts::TAU);
+    assert!((period - Vector2::new(1.0, 0.0)).norm() < 1.0e-8);
+}
+
+#[test]
+#[should_panic(expected = "Tolerance must be positive")]
+fn dormand_prince_rejects_non_positive_tolerance() {
+    let _ = DormandPrince::new(0.0);
+}
+
+#[test]
+fn leapfrog_energy_does_not_drift() {
+    let energy = |state: &Vector2<f64>| state.norm_squared() / 2.0;
+    let initial = Vector2::new(1.0, 0.0);
+    let mut state = initial;
+    let mut deviation = 0.0_f64;
+    for i in 0..100_000 {
+        state = Leapfrog::new().step(&Oscillator, f64::from(i) * 0.1, state, 0.1);
+        deviation = deviation.max((energy(&state) - energy(&initial)).abs());
+    }
+    // The energy error of the symplectic scheme stays bounded by O(dt^2) rather than growing with time
+    assert!(deviation < 5.0e-3, "energy deviation {deviation}");
+}
+
+#[test]
+fn flow_maps_advance_non_autonomous_flows_through_time() {
+    let map = FlowMap::new(Clock, Rk4::new(), 0.5);
+    let at = |n| Attractor::<f64, Vector1<f64>>::iterate_at(&map, n, Vector1::new(0.0), &mut rand::rng(), None).x;
+    assert!((at(0) - 0.125).abs() < 1.0e-12);
+    assert!((at(3) - 0.875).abs() < 1.0e-12);
+}
+
+/// Checks that a flow is stationary at `point`, and that flow maps leave it in place.
+fn assert_fixed<F: Flow<f64, 3>>(flow: F, point: Vector3<f64>) {
+    assert!(flow.derivative(0.0, &point).norm() < 1.0e-9, "derivative at {point}");
+    let map = FlowMap::new(flow, Rk4::new(), 0.01);
+    let next: SVector<f64, 3> = map.iterate(point);
+    assert!((next - point).norm() < 1.0e-9);
+}
+
+#[test]
+fn lorenz_fixed_points() {
+    let (rho, beta): (f64, f64) = (28.0, 8.0 / 3.0);
+    let r = (beta * (rho - 1.0)).sqrt();
+    assert_fixed(Lorenz::new(10.0, rho, beta), Vector3::zeros());
+    assert_fixed(Lorenz::new(10.0, rho, beta), Vector3::new(r, r, rho - 1.0));
+    assert_fixed(Lorenz::new(10.0, rho, beta), Vector3::new(-r, -r, rho - 1.0));
+}
+
+#[test]
+fn rossler_fixed_point() {
+    let (a, b, c): (f64, f64, f64) = (0.2, 0.2, 5.7);
+    let x = (c - (c * c - 4.0 * a * b).sqrt()) / 2.0;
+    assert_fixed(Rossler::new(a, b, c), Vector3::new(x, -x / a, x / a));
+}
+
+#[test]
+fn thomas_fixed_point() {
+    assert_fixed(Thomas::new(0.208_186), Vector3::zeros());
+}
+
+#[test]
+fn aizawa_fixed_point() {
+    let (a, c) = (0.95, 0.6);
+    // The fixed point on the z-axis is the root of c + a * z - z^3 / 3 between one and two
+    let cubic = |z: f64| c + a * z - z.powi(3) / 3.0;
+    let (mut low, mut high) = (1.0, 2.0);
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if cubic(mid) > 0.0 { low = mid } else { high = mid }
+    }
+    assert_fixed(Aizawa::new(a, 0.7, c, 3.5, 0.25, 0.1), Vector3::new(0.0, 0.0, low));
+}
+
+#[test]
+fn halvorsen_fixed_points() {
+    let a = 1.89;
+    assert_fixed(Halvorsen::new(a), Vector3::zeros());
+    assert_fixed(Halvorsen::new(a), Vector3::from_element(-(a + 8.0)));
+}
+
+#[test]
+fn chen_fixed_points() {
+    let (a, b, c): (f64, f64, f64) = (35.0, 3.0, 28.0);
+    let r = (b * (2.0 * c - a)).sqrt();
+    assert_fixed(Chen::new(a, b, c), Vector3::zeros());
+    assert_fixed(Chen::new(a, b, c), Vector3::new(r, r, 2.0 * c - a));
+    assert_fixed(Chen::new(a, b, c), Vector3::new(-r, -r, 2.0 * c - a));
+}
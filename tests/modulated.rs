@@ -0,0 +1,60 @@
+//! Modulated attractors must apply the member of their family scheduled at each iteration index.
+
+use attract::{Affine, Attractor, Modulated, Schedule};
+use nalgebra::Complex;
+
+/// Builds an attractor translating the real coordinate by the scheduled value.
+fn translation(schedule: Schedule<f64>) -> Modulated<f64, Affine<f64>> {
+    Modulated::new(schedule, |c| Affine::new(1.0, 0.0, c, 0.0, 1.0, 0.0))
+}
+
+/// Returns the translation applied at iteration `n`.
+fn applied(attractor: &Modulated<f64, Affine<f64>>, n: usize) -> f64 {
+    attractor.iterate_at(n, Complex::new(0.0, 0.0), &mut rand::rng(), None).re
+}
+
+#[test]
+fn ramp_drifts_with_the_iteration_index() {
+    let ramp = translation(Schedule::Ramp {
+        start: 1.0,
+        end: 2.0,
+        length: 10.0,
+    });
+    assert_eq!(applied(&ramp, 0), 1.0);
+    assert_eq!(applied(&ramp, 5), 1.5);
+    assert_eq!(applied(&ramp, 10), 2.0);
+    assert_eq!(applied(&ramp, 100), 2.0);
+}
+
+#[test]
+fn sine_and_square_follow_their_period() {
+    let sine = translation(Schedule::Sine {
+        mean: 1.0,
+        amplitude: 0.5,
+        period: 8.0,
+    });
+    assert!((applied(&sine, 2) - 1.5).abs() < 1e-12);
+    assert!((applied(&sine, 6) - 0.5).abs() < 1e-12);
+
+    let square = translation(Schedule::Square {
+        high: 3.0,
+        low: -3.0,
+        period: 4.0,
+    });
+    let values: Vec<f64> = (0..8).map(|n| applied(&square, n)).collect();
+    assert_eq!(values, [3.0, 3.0, -3.0, -3.0, 3.0, 3.0, -3.0, -3.0]);
+}
+
+#[test]
+fn custom_schedule_receives_the_iteration_index() {
+    let custom = translation(Schedule::Custom(Box::new(|n| n as f64)));
+    for n in [0, 1, 7, 1000] {
+        assert_eq!(applied(&custom, n), n as f64);
+    }
+}
+
+#[test]
+fn iterate_starts_the_schedule() {
+    let custom = translation(Schedule::Custom(Box::new(|n| n as f64 + 0.5)));
+    assert_eq!(custom.iterate(Complex::new(0.0, 0.0)).re, 0.5);
+}
@@ -0,0 +1,90 @@
+//! Parametric attractors must rebuild from their own parameter values, and reject values of the wrong length.
+
+use attract::{
+    Aizawa, Attractor, Bedhead, Chen, Chirikov, Clifford, DeJong, Duffing, Error, Expression, FlowMap, GumowskiMira, Halvorsen,
+    Henon, Ikeda, Julia, Lorenz, LotkaVolterra, Lozi, Parametric, Pickover, Rk4, Rossler, Svensson, SymmetricIcon,
+    SymmetricQuilt, Thomas, Tinkerbell, Zaslavsky,
+};
+use nalgebra::Complex;
+
+/// Asserts that an attractor reports consistent parameters, round-trips its values and rejects wrong counts.
+fn assert_parametric<P: Parametric<f64>>(attractor: &P) {
+    let count = attractor.num_parameters();
+    assert!(count > 0);
+    assert_eq!(attractor.parameter_values().len(), count);
+    assert_eq!(attractor.parameter_ranges().len(), count);
+
+    let values = attractor.parameter_values();
+    assert_eq!(attractor.with_parameters(&values).unwrap().parameter_values(), values);
+
+    let midpoints: Vec<f64> = attractor
+        .parameter_ranges()
+        .iter()
+        .map(|&[min, max]| (min + max) / 2.0)
+        .collect();
+    let rebuilt = attractor.with_parameters(&midpoints).unwrap();
+    assert_eq!(rebuilt.parameter_values(), midpoints);
+    assert_eq!(rebuilt.parameter_names(), attractor.parameter_names());
+
+    for wrong in [0, count - 1, count + 1] {
+        if wrong != count {
+            assert_eq!(
+                attractor.with_parameters(&vec![0.5; wrong]).err(),
+                Some(Error::ParameterCount {
+                    expected: count,
+                    found: wrong,
+                })
+            );
+        }
+    }
+}
+
+#[test]
+fn maps_round_trip_their_parameters() {
+    assert_parametric(&Bedhead::new(-0.81, -0.92));
+    assert_parametric(&Chirikov::new(0.97));
+    assert_parametric(&Clifford::new(-1.4, 1.6, 1.0, 0.7));
+    assert_parametric(&DeJong::new(1.4, -2.3, 2.4, -2.1));
+    assert_parametric(&Duffing::new(2.75, 0.2));
+    assert_parametric(&GumowskiMira::new(0.008, 0.05, -0.496));
+    assert_parametric(&Henon::new(1.4, 0.3));
+    assert_parametric(&Ikeda::new(0.9));
+    assert_parametric(&Julia::new(Complex::new(-0.8, 0.156)));
+    assert_parametric(&LotkaVolterra::new(2.9, 0.3));
+    assert_parametric(&Lozi::new(1.7, 0.5));
+    assert_parametric(&Pickover::new(-1.4, 1.6, 1.0, 0.7));
+    assert_parametric(&Svensson::new(1.4, 1.56, 1.4, -6.56));
+    assert_parametric(&SymmetricIcon::new(-2.08, 1.0, -0.1, 0.167, 0.0, 7));
+    assert_parametric(&SymmetricQuilt::new(-0.59, 0.2, 0.1, -0.33, 0.0, 2.0, 0.0));
+    assert_parametric(&Tinkerbell::new(0.9, -0.6013, 2.0, 0.5));
+    assert_parametric(&Zaslavsky::new(7.0, 0.2, 0.01));
+}
+
+#[test]
+fn flows_round_trip_their_parameters() {
+    assert_parametric(&Aizawa::new(0.95, 0.7, 0.6, 3.5, 0.25, 0.1));
+    assert_parametric(&Chen::new(35.0, 3.0, 28.0));
+    assert_parametric(&Halvorsen::new(1.89));
+    assert_parametric(&Lorenz::new(10.0, 28.0, 8.0 / 3.0));
+    assert_parametric(&Rossler::new(0.2, 0.2, 5.7));
+    assert_parametric(&Thomas::new(0.208_186));
+    assert_parametric(&FlowMap::new(Lorenz::new(10.0, 28.0, 8.0 / 3.0), Rk4::new(), 0.01));
+    assert_parametric(&Box::new(Clifford::new(-1.4, 1.6, 1.0, 0.7)));
+}
+
+#[test]
+fn expressions_round_trip_their_parameters() {
+    let expression = Expression::planar(
+        "sin(a * y) + c * cos(a * x)",
+        "sin(b * x) + d * cos(b * y)",
+        &[("a", -1.4), ("b", 1.6), ("c", 1.0), ("d", 0.7)],
+    )
+    .unwrap();
+    assert_parametric(&expression);
+
+    // The rebuilt expression iterates with the new parameter values
+    let clifford = Clifford::new(-1.0, 1.5, 0.5, 0.25);
+    let rebuilt = expression.with_parameters(&[-1.0, 1.5, 0.5, 0.25]).unwrap();
+    let p = Complex::new(0.1, 0.2);
+    assert!((rebuilt.iterate(p) - clifford.iterate(p)).norm() < 1e-12);
+}
@@ -0,0 +1,94 @@
+//! Renders must report their progress to the observer and stop early when cancelled.
+
+use std::sync::{Arc, Mutex};
+
+use attract::{Callback, Cancellation, Clifford, Error, Settings, SettingsBuilder, Silent, render, try_render};
+
+/// Starts building small seeded settings rendering 1000 samples in a single group.
+fn builder() -> SettingsBuilder<f64> {
+    Settings::builder(Clifford::new(-1.4, 1.6, 1.0, 0.7))
+        .resolution([32, 32])
+        .num_samples(1000)
+        .num_groups(1)
+        .seed(42)
+        .max_iter(100)
+        .warmup(10)
+}
+
+/// Every `(completed, total)` pair passed to a callback, in order.
+type Calls = Arc<Mutex<Vec<(u64, u64)>>>;
+
+/// Callback observer recording every pair it is given.
+fn recorder() -> (Calls, Callback<impl Fn(u64, u64) + Send + Sync>) {
+    let calls: Calls = Arc::default();
+    let recorded = Arc::clone(&calls);
+    let callback = Callback::new(move |completed, total| recorded.lock().unwrap().push((completed, total)));
+    (calls, callback)
+}
+
+#[test]
+fn callback_receives_monotone_progress_reaching_the_total() {
+    let (calls, callback) = recorder();
+    let _ = render(&builder().progress(callback).build().unwrap());
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.first(), Some(&(0, 1000)));
+    assert_eq!(calls.last(), Some(&(1000, 1000)));
+    assert!(calls.iter().all(|&(_, total)| total == 1000));
+    assert!(calls.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn callback_progress_reaches_the_total_across_groups() {
+    let (calls, callback) = recorder();
+    let _ = render(&builder().num_groups(7).progress(callback).build().unwrap());
+
+    let calls = calls.lock().unwrap();
+    let completed: Vec<u64> = calls.iter().map(|&(completed, _)| completed).collect();
+    assert_eq!(completed.iter().max(), Some(&1000));
+    assert!(completed.iter().skip(1).all(|&count| count > 0));
+}
+
+#[test]
+fn cancellation_mid_render_stops_early() {
+    // The callback cancels the render once the first 100 samples are reported.
+    let cancellation = Cancellation::new();
+    let token = cancellation.clone();
+    let calls: Calls = Arc::default();
+    let recorded = Arc::clone(&calls);
+    let settings = builder()
+        .cancellation(cancellation.clone())
+        .progress(Callback::new(move |completed, total| {
+            recorded.lock().unwrap().push((completed, total));
+            if completed >= 100 {
+                token.cancel();
+            }
+        }))
+        .build()
+        .unwrap();
+
+    let partial: u64 = render(&settings).iter().map(|&count| u64::from(count)).sum();
+    assert!(cancellation.is_cancelled());
+    assert_eq!(calls.lock().unwrap().last(), Some(&(100, 1000)));
+    assert!(partial <= 100 * 100);
+
+    let complete: u64 = render(&builder().progress(Silent::new()).build().unwrap())
+        .iter()
+        .map(|&count| u64::from(count))
+        .sum();
+    assert!(partial < complete);
+    assert_eq!(try_render(&settings).err(), Some(Error::Cancelled));
+}
+
+#[test]
+fn default_progress_renders_with_and_without_indicatif() {
+    let counts = try_render(&builder().build().unwrap()).unwrap();
+    assert!(counts.iter().any(|&count| count > 0));
+}
+
+#[cfg(feature = "indicatif")]
+#[test]
+fn bar_progress_renders() {
+    let counts = try_render(&builder().progress(attract::Bar::new()).build().unwrap()).unwrap();
+    assert!(counts.iter().any(|&count| count > 0));
+}
@@ -0,0 +1,65 @@
+//! Cameras must project states onto the image plane through their documented rotation and lens.
+
+use attract::{Camera, Projection};
+use nalgebra::{Complex, Vector3, Vector4};
+use std::f64::consts::FRAC_PI_2;
+
+/// Asserts that a projection lands within rounding error of the expected point.
+fn assert_projects(projected: Option<Complex<f64>>, expected: Complex<f64>) {
+    let point = projected.expect("The point should be visible");
+    assert!((point - expected).norm() < 1.0e-12, "{point} is not {expected}");
+}
+
+#[test]
+fn orthographic_camera_discards_depth_about_its_centre() {
+    let camera = Camera::orthographic(Vector3::new(1.0, 2.0, 3.0));
+    assert_projects(camera.project(&Vector3::new(2.0, 4.0, -10.0)), Complex::new(1.0, 2.0));
+
+    // Axes beyond the third are discarded as well
+    let camera = Camera::orthographic(Vector4::zeros());
+    assert_projects(camera.project(&Vector4::new(1.0, 2.0, 3.0, 4.0)), Complex::new(1.0, 2.0));
+}
+
+#[test]
+fn rotation_turns_camera_axes() {
+    let camera = Camera::orthographic(Vector3::zeros()).rotate(0, 1, FRAC_PI_2);
+    assert_projects(camera.project(&Vector3::new(1.0, 0.0, 0.0)), Complex::new(0.0, 1.0));
+    assert_projects(camera.project(&Vector3::new(0.0, 1.0, 0.0)), Complex::new(-1.0, 0.0));
+}
+
+#[test]
+fn perspective_camera_magnifies_nearer_points() {
+    let camera = Camera::perspective(Vector3::zeros(), 2.0);
+    assert_projects(camera.project(&Vector3::new(1.0, 1.0, 0.0)), Complex::new(1.0, 1.0));
+    assert_projects(camera.project(&Vector3::new(1.0, 1.0, -1.0)), Complex::new(2.0, 2.0));
+    assert_projects(camera.project(&Vector3::new(1.0, 1.0, 2.0)), Complex::new(0.5, 0.5));
+    assert!(camera.project(&Vector3::new(1.0, 1.0, -3.0)).is_none());
+}
+
+#[test]
+fn look_at_camera_centres_its_target() {
+    let camera = Camera::look_at(Vector3::new(0.0, -5.0, 0.0), Vector3::zeros(), Vector3::new(0.0, 0.0, 1.0));
+    assert_projects(camera.project(&Vector3::zeros()), Complex::new(0.0, 0.0));
+    // The x-axis points right and the z-axis up, with points halfway to the eye magnified twofold
+    assert_projects(camera.project(&Vector3::new(1.0, 0.0, 2.0)), Complex::new(1.0, 2.0));
+    assert_projects(camera.project(&Vector3::new(1.0, -2.5, 2.0)), Complex::new(2.0, 4.0));
+    assert!(camera.project(&Vector3::new(0.0, -6.0, 0.0)).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Distance must be positive")]
+fn perspective_camera_rejects_non_positive_distance() {
+    let _ = Camera::perspective(Vector3::<f64>::zeros(), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Eye and target must be distinct")]
+fn look_at_camera_rejects_eye_at_target() {
+    let _ = Camera::look_at(Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 1.0), Vector3::z());
+}
+
+#[test]
+#[should_panic(expected = "Up direction must not be parallel")]
+fn look_at_camera_rejects_up_along_view() {
+    let _ = Camera::look_at(Vector3::new(0.0, 0.0, -5.0), Vector3::<f64>::zeros(), Vector3::z());
+}
@@ -0,0 +1,115 @@
+//! Seeded renders must produce identical density images.
+
+use attract::{
+    Accumulation, Attractor, Channel, Clifford, Ifs, Layers, Perturbed, RandomSwitch, Settings, Silent, render, render_channels,
+};
+use rayon::ThreadPoolBuilder;
+
+/// Every accumulation strategy, all of which must produce the same counts.
+const STRATEGIES: [Accumulation; 3] = [Accumulation::PerGroup, Accumulation::PerThread, Accumulation::Shared];
+
+/// Builds small seeded settings for an attractor.
+fn settings<A: Attractor<f64> + Send + Sync + 'static>(attractor: A, step_size: Option<f64>) -> Settings<f64> {
+    let builder = Settings::builder(attractor)
+        .resolution([64, 64])
+        .scale(8.0)
+        .num_samples(64)
+        .num_groups(8)
+        .seed(42)
+        .max_iter(200)
+        .warmup(10)
+        .progress(Silent::new());
+    match step_size {
+        Some(dt) => builder.step_size(dt),
+        None => builder,
+    }
+    .build()
+    .unwrap()
+}
+
+/// Asserts that two renders of the same seeded settings are identical.
+fn assert_reproducible<A: Attractor<f64> + Clone + Send + Sync + 'static>(attractor: A, step_size: Option<f64>) {
+    let first = render(&settings(attractor.clone(), step_size));
+    let second = render(&settings(attractor, step_size));
+    assert!(first.iter().any(|&count| count > 0));
+    assert_eq!(first, second);
+}
+
+#[test]
+fn ifs_with_step_size_is_reproducible() {
+    assert_reproducible(Ifs::barnsley_fern(), Some(0.01));
+}
+
+#[test]
+fn random_switch_with_step_size_is_reproducible() {
+    let switch = RandomSwitch::new(vec![
+        (Clifford::new(-1.4, 1.6, 1.0, 0.7), 1.0),
+        (Clifford::new(1.7, 1.7, 0.6, 1.2), 2.0),
+    ]);
+    assert_reproducible(switch, Some(0.01));
+}
+
+#[test]
+fn perturbed_with_step_size_is_reproducible() {
+    let perturbed = Perturbed::new(Clifford::new(-1.4, 1.6, 1.0, 0.7), 0.01);
+    assert_reproducible(perturbed, Some(0.01));
+}
+
+#[test]
+fn perturbed_without_step_size_is_reproducible() {
+    let perturbed = Perturbed::new(Clifford::new(-1.4, 1.6, 1.0, 0.7), 0.01);
+    assert_reproducible(perturbed, None);
+}
+
+#[test]
+fn prime_sample_counts_render_every_sample() {
+    let settings = Settings::builder(Clifford::new(-1.4, 1.6, 1.0, 0.7))
+        .resolution([64, 64])
+        .num_samples(61)
+        .seed(42)
+        .max_iter(100)
+        .warmup(10)
+        .progress(Silent::new())
+        .build()
+        .unwrap();
+    let expected = settings.num_samples * settings.max_iter;
+
+    // Every sample plots all of its iterations, whichever group renders it
+    assert_eq!(render(&settings).sum() as usize, expected);
+    let mut grouped = settings.clone();
+    grouped.num_groups = 7;
+    assert_eq!(render(&grouped).sum() as usize, expected);
+}
+
+/// Runs a closure on a dedicated pool with the given number of threads.
+fn with_threads<R: Send>(num_threads: usize, f: impl FnOnce() -> R + Send) -> R {
+    ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap().install(f)
+}
+
+#[test]
+fn seeded_renders_match_across_thread_counts_and_strategies() {
+    let reference = render(&settings(Clifford::new(-1.4, 1.6, 1.0, 0.7), None));
+    assert!(reference.iter().any(|&count| count > 0));
+
+    for num_threads in [1, 2, 4] {
+        for accumulation in STRATEGIES {
+            let mut settings = settings(Clifford::new(-1.4, 1.6, 1.0, 0.7), None);
+            settings.accumulation = accumulation;
+            let counts = with_threads(num_threads, || render(&settings));
+            assert_eq!(counts, reference, "{accumulation:?} on {num_threads} threads");
+        }
+    }
+}
+
+#[test]
+fn channel_counts_match_across_strategies() {
+    let reference = render(&settings(Clifford::new(-1.4, 1.6, 1.0, 0.7), None));
+
+    for accumulation in STRATEGIES {
+        let mut settings = settings(Clifford::new(-1.4, 1.6, 1.0, 0.7), None);
+        settings.accumulation = accumulation;
+        let layers: Layers<f64> = with_threads(4, || render_channels(&settings, &[Channel::Iteration]));
+        assert_eq!(layers.counts().sum(), reference.sum(), "{accumulation:?}");
+        assert_eq!(layers.counts(), &reference, "{accumulation:?}");
+    }
+}
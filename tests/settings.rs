@@ -0,0 +1,117 @@
+//! Settings must be validated when built, reporting each problem with its own error.
+
+use attract::{Clifford, Error, Escape, Generator, Settings, SettingsBuilder, Silent};
+use nalgebra::Complex;
+
+/// Starts building small settings which are valid until modified.
+fn builder() -> SettingsBuilder<f64> {
+    Settings::builder(Clifford::new(-1.4, 1.6, 1.0, 0.7))
+        .resolution([16, 16])
+        .num_samples(16)
+        .num_groups(2)
+        .progress(Silent::new())
+}
+
+/// Builds the settings, returning the error if they are invalid.
+fn error(builder: SettingsBuilder<f64>) -> Option<Error> {
+    builder.build().err()
+}
+
+#[test]
+fn valid_settings_are_accepted() {
+    assert_eq!(error(builder()), None);
+    assert_eq!(error(builder().step_size(0.01).escape(Escape::Bounded(4.0))), None);
+}
+
+#[test]
+fn zero_resolution_is_rejected() {
+    assert_eq!(error(builder().resolution([0, 16])), Some(Error::ZeroResolution([0, 16])));
+    assert_eq!(error(builder().resolution([16, 0])), Some(Error::ZeroResolution([16, 0])));
+}
+
+#[test]
+fn zero_groups_are_rejected() {
+    assert_eq!(error(builder().num_groups(0)), Some(Error::ZeroGroups));
+}
+
+#[test]
+fn invalid_scale_is_rejected() {
+    assert_eq!(error(builder().scale(0.0)), Some(Error::InvalidScale(0.0)));
+    assert_eq!(error(builder().scale(-1.0)), Some(Error::InvalidScale(-1.0)));
+    assert_eq!(
+        error(builder().scale(f64::INFINITY)),
+        Some(Error::InvalidScale(f64::INFINITY))
+    );
+}
+
+#[test]
+fn invalid_offset_is_rejected() {
+    assert_eq!(
+        error(builder().offset([f64::INFINITY, 0.0])),
+        Some(Error::InvalidOffset([f64::INFINITY, 0.0]))
+    );
+    assert!(matches!(error(builder().offset([0.0, f64::NAN])), Some(Error::InvalidOffset([_, im])) if im.is_nan()));
+}
+
+#[test]
+fn invalid_step_size_is_rejected() {
+    assert_eq!(error(builder().step_size(0.0)), Some(Error::InvalidStepSize(0.0)));
+    assert_eq!(error(builder().step_size(-0.01)), Some(Error::InvalidStepSize(-0.01)));
+}
+
+#[test]
+fn invalid_escape_radius_is_rejected() {
+    assert_eq!(
+        error(builder().escape(Escape::Escaping(0.0))),
+        Some(Error::InvalidEscapeRadius(0.0))
+    );
+    assert_eq!(
+        error(builder().escape(Escape::Bounded(f64::INFINITY))),
+        Some(Error::InvalidEscapeRadius(f64::INFINITY))
+    );
+}
+
+#[test]
+fn invalid_generator_is_rejected() {
+    let aabb = Generator::Aabb {
+        centre: Complex::new(0.0, 0.0),
+        half_size: Complex::new(1.0, 0.0),
+    };
+    assert_eq!(
+        error(builder().generator(aabb)),
+        Some(Error::InvalidGenerator {
+            parameter: "half height",
+            value: 0.0,
+        })
+    );
+
+    let circle = Generator::Circle {
+        centre: Complex::new(0.0, 0.0),
+        radius: -1.0,
+    };
+    assert_eq!(
+        error(builder().generator(circle)),
+        Some(Error::InvalidGenerator {
+            parameter: "radius",
+            value: -1.0,
+        })
+    );
+
+    let gaussian = Generator::Gaussian {
+        centre: Complex::new(0.0, 0.0),
+        std_dev: f64::NAN,
+    };
+    assert!(matches!(
+        error(builder().generator(gaussian)),
+        Some(Error::InvalidGenerator { parameter: "standard deviation", value }) if value.is_nan()
+    ));
+}
+
+#[test]
+fn the_first_problem_is_reported() {
+    assert_eq!(
+        error(builder().resolution([0, 0]).num_groups(0).scale(0.0)),
+        Some(Error::ZeroResolution([0, 0]))
+    );
+    assert_eq!(error(builder().num_groups(0).scale(0.0)), Some(Error::ZeroGroups));
+}
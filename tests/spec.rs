@@ -0,0 +1,156 @@
+//! Invalid specifications must be rejected with an error rather than a panic.
+
+#![cfg(feature = "serde")]
+
+use attract::{AttractorSpec, BuiltAttractor, CameraSpec, Error, Escape, Planar, Registry, RenderConfig, SpecError};
+
+/// Parses an attractor specification from YAML.
+fn attractor(yaml: &str) -> AttractorSpec<f64> {
+    serde_yaml::from_str(yaml).unwrap()
+}
+
+/// Builds an attractor specification, returning the error if it cannot be built.
+fn build(spec: &AttractorSpec<f64>) -> Result<BuiltAttractor<f64>, SpecError> {
+    spec.build(0, 1, &Registry::new())
+}
+
+#[test]
+fn ifs_without_maps_is_rejected() {
+    let spec = attractor("!Ifs\nmaps: []\n");
+    assert_eq!(build(&spec).err(), Some(SpecError::InvalidWeights));
+}
+
+#[test]
+fn ifs_with_zero_weights_is_rejected() {
+    let spec = attractor("!Ifs\nmaps:\n  - [0.5, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0]\n");
+    assert_eq!(build(&spec).err(), Some(SpecError::InvalidWeights));
+}
+
+#[test]
+fn random_switch_with_zero_weights_is_rejected() {
+    let spec = attractor("!RandomSwitch\nattractors:\n  - attractor: !ArnoldCat\n    weight: 0.0\n");
+    assert_eq!(build(&spec).err(), Some(SpecError::InvalidWeights));
+}
+
+#[test]
+fn camera_rotation_outside_state_is_rejected() {
+    let spec: CameraSpec<f64> =
+        serde_yaml::from_str("!Orthographic\ncentre: [0.0, 0.0, 0.0]\nrotations:\n  - axes: [0, 3]\n    angle: !Fixed 0.5\n")
+            .unwrap();
+    assert_eq!(spec.build(0, 1).err(), Some(SpecError::InvalidRotation([0, 3])));
+}
+
+#[test]
+fn flow_integrator_defaults_to_rk4() {
+    let spec = attractor("!Lorenz\nsigma: !Fixed 10.0\nrho: !Fixed 28.0\nbeta: !Fixed 2.6666667\n");
+    assert!(matches!(build(&spec), Ok(BuiltAttractor::Spatial(_))));
+}
+
+#[test]
+fn flow_integrator_and_step_size_are_configurable() {
+    let spec = attractor(
+        "!Lorenz\nsigma: !Fixed 10.0\nrho: !Fixed 28.0\nbeta: !Fixed 2.6666667\nintegrator: !DormandPrince\n  tolerance: 1.0e-6\nstep_size: 0.005\n",
+    );
+    assert!(matches!(build(&spec), Ok(BuiltAttractor::Spatial(_))));
+}
+
+#[test]
+fn flow_with_non_positive_step_size_is_rejected() {
+    let spec = attractor("!Thomas\nb: !Fixed 0.2\nstep_size: 0.0\n");
+    assert_eq!(
+        build(&spec).err(),
+        Some(SpecError::NonPositiveParameter("step_size".to_owned()))
+    );
+}
+
+#[test]
+fn perspective_camera_with_non_positive_distance_is_rejected() {
+    let spec: CameraSpec<f64> =
+        serde_yaml::from_str("!Perspective\ncentre: [0.0, 0.0, 0.0]\ndistance: 0.0\nrotations: []\n").unwrap();
+    assert_eq!(
+        spec.build(0, 1).err(),
+        Some(SpecError::NonPositiveParameter("distance".to_owned()))
+    );
+}
+
+#[test]
+fn degenerate_look_at_cameras_are_rejected() {
+    let camera = |yaml: &str| serde_yaml::from_str::<CameraSpec<f64>>(yaml).unwrap().build(0, 1);
+    let at_target = camera("!LookAt\neye: [1.0, 2.0, 3.0]\ntarget: [1.0, 2.0, 3.0]\nup: [0.0, 0.0, 1.0]\n");
+    assert_eq!(at_target.err(), Some(SpecError::DegenerateCamera));
+    let looking_up = camera("!LookAt\neye: [0.0, 0.0, -5.0]\ntarget: [0.0, 0.0, 0.0]\nup: [0.0, 0.0, 1.0]\n");
+    assert_eq!(looking_up.err(), Some(SpecError::DegenerateCamera));
+    let level = camera("!LookAt\neye: [0.0, -5.0, 0.0]\ntarget: [0.0, 0.0, 0.0]\nup: [0.0, 0.0, 1.0]\n");
+    assert!(level.is_ok());
+}
+
+#[test]
+fn escape_time_maps_build_as_orbits() {
+    assert!(matches!(build(&attractor("!Mandelbrot\n")), Ok(BuiltAttractor::Orbit(_))));
+    assert!(matches!(
+        build(&attractor("!Multibrot\ndegree: 3\n")),
+        Ok(BuiltAttractor::Orbit(_))
+    ));
+    let julia = attractor("!Julia\nc_re: !Fixed -0.8\nc_im: !Fixed 0.156\n");
+    assert!(matches!(build(&julia), Ok(BuiltAttractor::Planar(_))));
+    let affine =
+        attractor("!Affine\na: !Fixed 0.5\nb: !Fixed 0.0\nc: !Fixed 0.0\nd: !Fixed 0.0\ne: !Fixed 0.5\nf: !Fixed 0.0\n");
+    assert!(matches!(build(&affine), Ok(BuiltAttractor::Planar(_))));
+}
+
+#[test]
+fn escape_time_maps_do_not_combine_with_other_states() {
+    let perturbed = attractor("!Perturbed\nattractor: !Mandelbrot\nstd_dev: !Fixed 0.1\n");
+    assert_eq!(
+        build(&perturbed).err(),
+        Some(SpecError::UnsupportedOrbit("Perturbed".to_owned()))
+    );
+    let compose = attractor("!Compose\nouter: !Mandelbrot\ninner: !ArnoldCat\n");
+    assert_eq!(build(&compose).err(), Some(SpecError::DimensionMismatch));
+    let alternate = attractor("!Alternate\nfirst: !Mandelbrot\nsecond: !Multibrot\n  degree: 3\n");
+    assert!(matches!(build(&alternate), Ok(BuiltAttractor::Orbit(_))));
+}
+
+/// Parses the render configuration of a file in `input`.
+fn config(name: &str) -> RenderConfig<f64> {
+    let path = format!("{}/input/{name}", env!("CARGO_MANIFEST_DIR"));
+    serde_yaml::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn configured_escape_reaches_the_settings() {
+    let config = config("buddhabrot.yaml");
+    let BuiltAttractor::Orbit(attractor) = config.attractor(0, &Registry::new()).unwrap() else {
+        panic!("The Mandelbrot map evolves orbits");
+    };
+    let settings = config.settings(attractor, Box::new(Planar::new())).unwrap();
+    assert_eq!(settings.escape, Some(Escape::Escaping(2.0)));
+    assert_eq!(settings.num_groups, 100);
+}
+
+#[test]
+fn invalid_configured_settings_are_rejected() {
+    let mut config = config("henon.yaml");
+    config.rendering.resolution = [0, 1024];
+    let BuiltAttractor::Planar(attractor) = config.attractor(0, &Registry::new()).unwrap() else {
+        panic!("The Henon map is planar");
+    };
+    let error = config.settings(attractor, Box::new(Planar::new())).err();
+    assert!(matches!(error, Some(Error::ZeroResolution(_))));
+}
+
+#[test]
+fn symmetric_icon_with_non_positive_degree_is_rejected() {
+    let spec = attractor(
+        "!SymmetricIcon\nlambda: !Fixed -2.08\nalpha: !Fixed 1.0\nbeta: !Fixed -0.1\ngamma: !Fixed 0.167\nomega: !Fixed 0.0\ndegree: 0\n",
+    );
+    assert_eq!(build(&spec).err(), Some(SpecError::NonPositiveParameter("degree".to_owned())));
+}
+
+#[test]
+fn multibrot_with_degree_below_two_is_rejected() {
+    assert_eq!(
+        build(&attractor("!Multibrot\ndegree: 1\n")).err(),
+        Some(SpecError::InvalidDegree(1))
+    );
+}
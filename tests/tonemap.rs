@@ -0,0 +1,27 @@
+//! Tone maps fitted to several frames must match a fit to the pooled pixels of every frame.
+
+use attract::ToneMap;
+use ndarray::{Array2, Axis, concatenate};
+
+#[test]
+fn fit_frames_matches_fit_of_pooled_frames() {
+    let first = Array2::from_shape_fn([8, 8], |(row, col)| ((row * 8 + col) * 7 % 23) as u32);
+    let second = Array2::from_shape_fn([8, 8], |(row, col)| ((row + col) * 5 % 11) as u32);
+    let pooled = concatenate(Axis(0), &[first.view(), second.view()]).unwrap();
+    let tone_map = ToneMap::<f64>::new().log().clip(0.1, 0.9).equalise(16);
+
+    let normalisation = tone_map.fit_frames(&[&first, &second]);
+    assert_eq!(normalisation, tone_map.fit(&pooled));
+    assert_eq!(normalisation.apply(&first), tone_map.fit(&pooled).apply(&first));
+}
+
+#[test]
+fn clip_interpolates_percentiles_of_repeated_counts() {
+    // Non-zero counts 2, 2, 3, 4, 6, so the 62.5th percentile lies halfway between 3 and 4
+    let counts = Array2::from_shape_vec([1, 6], vec![0_u32, 2, 2, 3, 4, 6]).unwrap();
+    let intensities = ToneMap::<f64>::new().clip(0.0, 0.625).apply(&counts);
+
+    assert_eq!(intensities[[0, 1]], 0.0);
+    assert!((intensities[[0, 3]] - 2.0 / 3.0).abs() < 1e-12);
+    assert_eq!(intensities[[0, 5]], 1.0);
+}